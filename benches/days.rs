@@ -0,0 +1,184 @@
+//! Benchmarks parse, part 1 and part 2 of every registered day that has an input file.
+//!
+//! `cargo bench --bench days -- [FILTER] [--save-baseline NAME] [--baseline NAME] [--time SECONDS]`
+//!
+//! Every step is sampled repeatedly and the median time per run is reported. Results are saved as
+//! a baseline in `target/aoc-bench/<name>.txt` (`base` by default) and compared with the previous
+//! results of that baseline. `--baseline NAME` compares with a saved baseline without overwriting it.
+
+use adv_code_2024::days::PUZZLES;
+use adv_code_2024::input::input_path;
+use adv_code_2024::Puzzle;
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+const DEFAULT_BASELINE: &str = "base";
+const SAMPLES: usize = 20;
+/// Changes of the median below this ratio are considered noise.
+const NOISE_THRESHOLD: f64 = 0.05;
+
+struct Config {
+    filter: Option<String>,
+    save: Option<String>,
+    compare: String,
+    time: Duration,
+}
+
+impl Config {
+    fn from_args() -> Result<Self> {
+        let mut config = Config {
+            filter: None,
+            save: Some(DEFAULT_BASELINE.to_string()),
+            compare: DEFAULT_BASELINE.to_string(),
+            time: Duration::from_secs(3),
+        };
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                // Passed by `cargo bench` to every bench target.
+                "--bench" => {}
+                "--save-baseline" => {
+                    let name = args.next().context("--save-baseline needs a name")?;
+                    config.compare = name.clone();
+                    config.save = Some(name);
+                }
+                "--baseline" => {
+                    config.compare = args.next().context("--baseline needs a name")?;
+                    config.save = None;
+                }
+                "--time" => {
+                    let value = args.next().context("--time needs seconds")?;
+                    config.time = Duration::from_secs_f64(value.parse().context(format!("Wrong time \"{value}\""))?);
+                }
+                arg if arg.starts_with("--") => return Err(anyhow!("Unknown flag \"{arg}\"")),
+                _ => config.filter = Some(arg),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Median time of one run of `f`. Runs are batched so that a sample is not dominated by the
+/// timer resolution, and `time` is spread over `SAMPLES` samples.
+fn measure<T>(time: Duration, mut f: impl FnMut() -> Result<T>) -> Result<Duration> {
+    let start = Instant::now();
+    black_box(f()?);
+    let once = start.elapsed().max(Duration::from_nanos(1));
+
+    let per_sample = time / SAMPLES as u32;
+    let (iterations, samples) = if once < per_sample {
+        ((per_sample.as_nanos() / once.as_nanos()) as u32, SAMPLES)
+    } else {
+        (1, (time.as_nanos() / once.as_nanos()).clamp(3, SAMPLES as u128) as usize)
+    };
+
+    let mut times = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(f()?);
+        }
+        times.push(start.elapsed() / iterations);
+    }
+    times.sort();
+    Ok(times[times.len() / 2])
+}
+
+fn bench_puzzle(puzzle: &Puzzle, content: &str, time: Duration) -> Result<Vec<(&'static str, Duration)>> {
+    let mut steps = vec![("parse", measure(time, || puzzle.parse(&mut content.as_bytes()))?)];
+    let input = puzzle.parse(&mut content.as_bytes())?;
+    steps.push(("part1", measure(time, || puzzle.part1(input.as_ref()))?));
+    if puzzle.has_part2 {
+        steps.push(("part2", measure(time, || puzzle.part2(input.as_ref()))?));
+    }
+    Ok(steps)
+}
+
+fn baseline_path(name: &str) -> PathBuf {
+    let target = env::var_os("CARGO_TARGET_DIR").map(PathBuf::from).unwrap_or_else(|| PathBuf::from("target"));
+    target.join("aoc-bench").join(format!("{name}.txt"))
+}
+
+/// Baseline lines are `<year>-<day> <step> <nanoseconds>`.
+fn load_baseline(name: &str) -> BTreeMap<(String, String), Duration> {
+    let content = fs::read_to_string(baseline_path(name)).unwrap_or_default();
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut values = line.split_whitespace();
+            let day = values.next()?.to_string();
+            let step = values.next()?.to_string();
+            let nanos = values.next()?.parse::<u64>().ok()?;
+            Some(((day, step), Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+fn save_baseline(name: &str, results: &BTreeMap<(String, String), Duration>) -> Result<()> {
+    let path = baseline_path(name);
+    fs::create_dir_all(path.parent().unwrap())?;
+    let content = results
+        .iter()
+        .map(|((day, step), time)| format!("{day} {step} {}\n", time.as_nanos()))
+        .collect::<String>();
+    fs::write(&path, content).with_context(|| format!("Can not write {}", path.display()))
+}
+
+fn change(baseline: Duration, current: Duration) -> (String, &'static str) {
+    let ratio = current.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+    let status = match ratio {
+        ratio if ratio > NOISE_THRESHOLD => "regressed",
+        ratio if ratio < -NOISE_THRESHOLD => "improved",
+        _ => "",
+    };
+    (format!("{:+.1}%", ratio * 100.0), status)
+}
+
+fn main() -> Result<()> {
+    let config = Config::from_args()?;
+    let baseline = load_baseline(&config.compare);
+    let mut results = load_baseline(config.save.as_deref().unwrap_or(&config.compare));
+    let mut regressed = vec![];
+
+    println!("{:<9}{:<7}{:>14}{:>14}{:>10}", "Day", "Step", "Baseline", "Current", "Change");
+    for puzzle in PUZZLES {
+        let day = format!("{}-{:02}", puzzle.year, puzzle.day);
+        if config.filter.as_ref().is_some_and(|x| !day.contains(x.as_str())) {
+            continue;
+        }
+        let Some(content) = fs::read_to_string(input_path(puzzle.year, puzzle.day)).ok() else {
+            continue;
+        };
+        let steps = bench_puzzle(puzzle, &content, config.time);
+        let Some(steps) = steps.inspect_err(|error| println!("{:<9}error: {error:#}", day)).ok() else {
+            continue;
+        };
+        for (step, current) in steps {
+            let key = (day.clone(), step.to_string());
+            let (change, status) = match baseline.get(&key) {
+                Some(baseline) => change(*baseline, current),
+                None => (String::new(), "new"),
+            };
+            let previous = baseline.get(&key).map(|x| format!("{x:.3?}")).unwrap_or_default();
+            let line = format!("{:<9}{:<7}{:>14}{:>14}{:>10}  {}", day, step, previous, format!("{current:.3?}"), change, status);
+            println!("{}", line.trim_end());
+            if status == "regressed" {
+                regressed.push(format!("{day} {step}"));
+            }
+            results.insert(key, current);
+        }
+    }
+
+    if let Some(name) = config.save {
+        save_baseline(&name, &results)?;
+    }
+    if !regressed.is_empty() {
+        println!("\nRegressed: {}", regressed.join(", "));
+    }
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const BIN_DIR: &str = "src/bin";
+const DAYS_DIR: &str = "src/days";
+const REGISTRY: &str = "src/days/mod.rs";
+const EXAMPLES_DIR: &str = "examples";
+
+/// Cargo only auto-discovers `src/bin/*.rs` and `src/bin/*/main.rs`. Every other solution file
+/// has to be registered as a `[[bin]]` target in Cargo.toml, otherwise it is silently never
+/// compiled. Fail the build instead.
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed={BIN_DIR}");
+    println!("cargo:rerun-if-changed={DAYS_DIR}");
+    println!("cargo:rerun-if-changed={EXAMPLES_DIR}");
+
+    let manifest = fs::read_to_string("Cargo.toml").expect("Can not read Cargo.toml");
+    let mut missing = vec![];
+    collect_missing(Path::new(BIN_DIR), &manifest, &mut missing);
+
+    if !missing.is_empty() {
+        missing.sort();
+        panic!(
+            "Solution files are not registered as [[bin]] targets in Cargo.toml: {}",
+            missing.join(", ")
+        );
+    }
+
+    let mut unregistered = collect_unregistered_days();
+    if !unregistered.is_empty() {
+        unregistered.sort();
+        panic!(
+            "Day modules are not declared in their year module or not listed in PUZZLES of {REGISTRY}: {}",
+            unregistered.join(", ")
+        );
+    }
+
+    let tests = generate_example_tests();
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+    fs::write(Path::new(&out_dir).join("examples.rs"), tests).expect("Can not write example tests");
+}
+
+/// Generates a test per example and part for tests/examples.rs. `examples/<year>/<day>-<n>.txt`
+/// is the input of an example, `examples/<year>/<day>-<n>.expected` has its answers as
+/// `part1: <answer>` and `part2: <answer>` lines, and a `params: key=value ...` line for the
+/// puzzle parameters of the example that differ from the real puzzle. Blocks separated by a blank
+/// line check the same input with other parameters, their tests get the block number as suffix.
+fn generate_example_tests() -> String {
+    let mut expected_files = vec![];
+    let years = fs::read_dir(EXAMPLES_DIR).expect("Can not read examples directory");
+    for year_dir in years.flatten().map(|x| x.path()).filter(|x| x.is_dir()) {
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+        let entries = fs::read_dir(&year_dir).expect("Can not read examples directory");
+        for path in entries.flatten().map(|x| x.path()) {
+            println!("cargo:rerun-if-changed={}", path.display());
+            if path.extension().is_some_and(|x| x == "expected") {
+                expected_files.push(path);
+            }
+        }
+    }
+    expected_files.sort();
+
+    let mut tests = String::new();
+    for path in expected_files {
+        let input = path.with_extension("txt");
+        if !input.exists() {
+            panic!("Example {} has no input file {}", path.display(), input.display());
+        }
+        let year = path.parent().and_then(|x| x.file_name()).unwrap().to_string_lossy().to_string();
+        let stem = path.file_stem().unwrap().to_string_lossy().to_string();
+        let Some((day, n)) = stem.split_once('-') else {
+            panic!("Example {} is not named <day>-<n>.expected", path.display());
+        };
+        let Ok(day_number) = day.parse::<u8>() else {
+            panic!("Example {} is not named <day>-<n>.expected", path.display());
+        };
+        let input = input.to_string_lossy().replace('\\', "/");
+        let content = fs::read_to_string(&path).expect("Can not read expected answers");
+        let blocks: Vec<&str> = content.split("\n\n").filter(|x| !x.trim().is_empty()).collect();
+        for (index, block) in blocks.iter().enumerate() {
+            let suffix = if index == 0 { String::new() } else { format!("_{}", index + 1) };
+            let mut params = "";
+            let mut answers = vec![];
+            for line in block.lines().filter(|x| !x.trim().is_empty()) {
+                let Some((key, value)) = line.split_once(':') else {
+                    panic!("Expected \"partN: <answer>\" in {}, got \"{line}\"", path.display());
+                };
+                match key.trim() {
+                    "params" => params = value.trim(),
+                    "part1" => answers.push((1, value.trim())),
+                    "part2" => answers.push((2, value.trim())),
+                    part => panic!("Unknown part \"{part}\" in {}", path.display()),
+                }
+            }
+            for (part, answer) in answers {
+                tests.push_str(&format!(
+                    "#[test]\nfn example_{year}_{day}_{n}_part{part}{suffix}() {{\n    check_example({year}, {day_number}, {input:?}, {params:?}, {part}, {answer:?});\n}}\n\n",
+                ));
+            }
+        }
+    }
+    tests
+}
+
+/// Every `src/days/yearXXXX/dayNN.rs` has to be declared as `pub mod dayNN;` in the year module
+/// and listed in `PUZZLES`, otherwise the day can not be run.
+fn collect_unregistered_days() -> Vec<String> {
+    let registry = fs::read_to_string(REGISTRY).expect("Can not read the registry of days");
+    let mut unregistered = vec![];
+    let years = fs::read_dir(DAYS_DIR).expect("Can not read days directory");
+    for year_dir in years.flatten().map(|x| x.path()).filter(|x| x.is_dir()) {
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+        let year = year_dir.file_name().unwrap().to_string_lossy().to_string();
+        let year_module = fs::read_to_string(year_dir.join("mod.rs")).unwrap_or_default();
+        let entries = fs::read_dir(&year_dir).expect("Can not read year directory");
+        for path in entries.flatten().map(|x| x.path()) {
+            let Some(day) = path.file_stem().map(|x| x.to_string_lossy().to_string()) else {
+                continue;
+            };
+            if !day.starts_with("day") || path.extension().is_none_or(|x| x != "rs") {
+                continue;
+            }
+            let declared = year_module.contains(&format!("pub mod {day};"));
+            let listed = registry.contains(&format!("{year}::{day}::"));
+            if !declared || !listed {
+                unregistered.push(path.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+    unregistered
+}
+
+fn collect_missing(dir: &Path, manifest: &str, missing: &mut Vec<String>) {
+    let entries = fs::read_dir(dir).expect("Can not read solutions directory");
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            println!("cargo:rerun-if-changed={}", path.display());
+            collect_missing(&path, manifest, missing);
+            continue;
+        }
+        if path.extension().is_none_or(|x| x != "rs") {
+            continue;
+        }
+        let relative = path.to_string_lossy().replace('\\', "/");
+        if is_auto_discovered(&path) {
+            continue;
+        }
+        if !manifest.contains(&format!("path = \"{relative}\"")) {
+            missing.push(relative);
+        }
+    }
+}
+
+fn is_auto_discovered(path: &Path) -> bool {
+    let parent = path.parent().unwrap_or(Path::new(""));
+    if parent == Path::new(BIN_DIR) {
+        return true;
+    }
+    path.file_name().is_some_and(|x| x == "main.rs")
+        && parent.parent() == Some(Path::new(BIN_DIR))
+}
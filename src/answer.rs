@@ -0,0 +1,129 @@
+use num_bigint::{BigInt, BigUint};
+use std::fmt::{Display, Formatter};
+
+/// Answer of a puzzle part.
+///
+/// The display is the canonical form that is submitted on the site and stored in
+/// `answers/<year>.toml`: integers in decimal, coordinates as `x,y` and lists joined with commas.
+/// Big integers that fit into `Int` are stored as `Int`, so the same number always compares equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    BigInt(BigInt),
+    Str(String),
+    Coord(i64, i64),
+    List(Vec<Answer>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::BigInt(value) => write!(f, "{value}"),
+            Answer::Str(value) => write!(f, "{value}"),
+            Answer::Coord(x, y) => write!(f, "{x},{y}"),
+            Answer::List(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                std::fmt::Result::Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::from(BigInt::from(value))
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        match i128::try_from(&value) {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::BigInt(value),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        Answer::from(BigInt::from(value))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from((x, y): (usize, usize)) -> Self {
+        Answer::Coord(x as i64, y as i64)
+    }
+}
+
+impl From<(i64, i64)> for Answer {
+    fn from((x, y): (i64, i64)) -> Self {
+        Answer::Coord(x, y)
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(values: Vec<T>) -> Self {
+        Answer::List(values.into_iter().map(|x| x.into()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!("3749", Answer::from(3749usize).to_string());
+        assert_eq!("-12", Answer::from(-12i64).to_string());
+        assert_eq!("co,de,ka,ta", Answer::from("co,de,ka,ta").to_string());
+        assert_eq!("6,1", Answer::from((6usize, 1usize)).to_string());
+        assert_eq!("4,6,3,5", Answer::from(vec![4usize, 6, 3, 5]).to_string());
+        assert_eq!("aa,bb", Answer::from(vec!["aa", "bb"]).to_string());
+    }
+
+    #[test]
+    fn test_big_int() {
+        assert_eq!(Answer::Int(42), Answer::from(BigUint::from(42u32)));
+        let big = BigUint::from(u128::MAX) * 10u32;
+        assert_eq!("3402823669209384634633746074317682114550", Answer::from(big).to_string());
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Directory with accepted answers, one file per year: `answers/<year>.toml`.
+pub const ANSWERS_DIR: &str = "answers";
+
+pub fn answers_path(year: u16) -> PathBuf {
+    PathBuf::from(ANSWERS_DIR).join(format!("{year}.toml"))
+}
+
+/// Accepted answers of one year by day and part, stored as a table per day:
+///
+/// ```toml
+/// [07]
+/// part1 = "3749"
+/// part2 = "11387"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u8, BTreeMap<u8, String>>,
+}
+
+impl Answers {
+    /// Answers recorded for the year, empty if nothing is recorded yet.
+    pub fn load(year: u16) -> Result<Self> {
+        let path = answers_path(year);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path).with_context(|| format!("Can not read {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Can not parse {}", path.display()))
+    }
+
+    pub fn save(&self, year: u16) -> Result<()> {
+        let path = answers_path(year);
+        fs::create_dir_all(ANSWERS_DIR).with_context(|| format!("Can not create {ANSWERS_DIR}"))?;
+        fs::write(&path, self.to_string()).with_context(|| format!("Can not write {}", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut answers = Self::default();
+        let mut day = None;
+        for (number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[').and_then(|x| x.strip_suffix(']')) {
+                let value = header.trim().parse::<u8>().context(format!("Line {}: wrong day \"{header}\"", number + 1))?;
+                day = Some(value);
+                continue;
+            }
+            let day = day.context(format!("Line {}: answer outside of a day table", number + 1))?;
+            let (key, value) = line
+                .split_once('=')
+                .context(format!("Line {}: expected `partN = \"answer\"`", number + 1))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                key => return Err(anyhow!("Line {}: unknown key \"{key}\"", number + 1)),
+            };
+            let value = value.trim();
+            let value = value.strip_prefix('"').and_then(|x| x.strip_suffix('"')).unwrap_or(value);
+            answers.set(day, part, value.to_string());
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days.get(&day).and_then(|x| x.get(&part)).map(|x| x.as_str())
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: String) {
+        self.days.entry(day).or_default().insert(part, answer);
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# Accepted answers, written by `aoc run --record`.")?;
+        for (day, parts) in self.days.iter() {
+            writeln!(f)?;
+            writeln!(f, "[{day:02}]")?;
+            for (part, answer) in parts.iter() {
+                writeln!(f, "part{part} = \"{answer}\"")?;
+            }
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(7, 2, "11387".to_string());
+        answers.set(7, 1, "3749".to_string());
+        answers.set(17, 1, "4,6,3,5,6,3,5,2,1,0".to_string());
+
+        let content = answers.to_string();
+        assert!(content.contains("[07]\npart1 = \"3749\"\npart2 = \"11387\"\n"));
+        assert_eq!(answers, Answers::parse(&content).unwrap());
+        assert_eq!(Some("4,6,3,5,6,3,5,2,1,0"), answers.get(17, 1));
+        assert_eq!(None, answers.get(17, 2));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("part1 = \"1\"").is_err());
+        assert!(Answers::parse("[01]\npart3 = \"1\"").is_err());
+        assert!(Answers::parse("[day]\n").is_err());
+    }
+}
@@ -0,0 +1,7 @@
+use adv_code_2024::cli::Command;
+use anyhow::Result;
+use std::env;
+
+fn main() -> Result<()> {
+    Command::parse(env::args().skip(1))?.execute()
+}
@@ -0,0 +1,523 @@
+use crate::answers::Answers;
+use crate::days::{self, PUZZLES};
+use crate::input::{input_path, open_path, resolve_input};
+use crate::parse::in_file;
+use crate::render::{Recording, Renderer};
+use crate::solution::run_puzzle;
+use crate::{Answer, Params, Puzzle};
+use anyhow::*;
+use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run <year> <day> [--part N] [--input PATH|-] [--param KEY=VALUE]...
+    aoc run <year> <day> [--part N] [--record]
+    aoc run --all [--record]
+    aoc dot <year> <day> [--input PATH|-] [--param KEY=VALUE]... [--output PATH]
+    aoc render <year> <day> [--input PATH|-] [--param KEY=VALUE]... [--output DIR]
+        [--scale N] [--fps N] [--every N] [--max-frames N]
+    aoc trace <year> <day> [--input PATH|-] [--param KEY=VALUE]...
+    aoc verify
+    aoc list
+
+--param sets a puzzle parameter that is not part of the input, like the room size of 2024/14
+when running an example. --record saves the answers of the default input files to
+answers/<year>.toml, verify checks every day with an input file against them. dot writes the
+graph of a day for Graphviz to output/<year>/<day>.dot, render draws the day as PNG images and
+GIF animations into output/<year>/<day>/, every cell 4 pixels wide by default. Animations keep
+one frame every N steps (1) up to a maximum (300) and play at 10 frames per second unless told
+otherwise. trace prints the program of a day like 2024/17 disassembled, followed by every
+instruction it executes.";
+
+const PART_FLAG: &str = "--part";
+/// Path to the input file, takes precedence over `AOC_INPUT`.
+const INPUT_FLAG: &str = "--input";
+const ALL_FLAG: &str = "--all";
+const RECORD_FLAG: &str = "--record";
+const PARAM_FLAG: &str = "--param";
+const OUTPUT_FLAG: &str = "--output";
+const SCALE_FLAG: &str = "--scale";
+const FPS_FLAG: &str = "--fps";
+const EVERY_FLAG: &str = "--every";
+const MAX_FRAMES_FLAG: &str = "--max-frames";
+const DEFAULT_SCALE: u32 = 4;
+
+/// Command of the `aoc` binary.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    /// Runs one day. Both parts if `part` is `None`, `input` falls back to `resolve_input`.
+    Run {
+        year: u16,
+        day: u8,
+        part: Option<u8>,
+        input: Option<PathBuf>,
+        params: Params,
+        record: bool,
+    },
+    /// Runs every registered day on its default input file.
+    RunAll { record: bool },
+    /// Writes the graph of one day as a Graphviz DOT file, `output` defaults to `dot_path`.
+    Dot {
+        year: u16,
+        day: u8,
+        input: Option<PathBuf>,
+        params: Params,
+        output: Option<PathBuf>,
+    },
+    /// Draws one day as PNG images into `output`, which defaults to `render_path`.
+    Render {
+        year: u16,
+        day: u8,
+        input: Option<PathBuf>,
+        params: Params,
+        output: Option<PathBuf>,
+        scale: u32,
+        recording: Recording,
+    },
+    /// Prints the disassembled program of one day and the instructions it executes.
+    Trace {
+        year: u16,
+        day: u8,
+        input: Option<PathBuf>,
+        params: Params,
+    },
+    /// Runs every day with an input file and compares the answers with the recorded ones.
+    Verify,
+    /// Lists registered days and whether their input file exists.
+    List,
+}
+
+impl Command {
+    /// Parses the command from the arguments without the binary name.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+        let mut args = args.into_iter();
+        match args.next().as_deref() {
+            Some("run") => Self::parse_run(args),
+            Some("dot") => Self::parse_export(args, false),
+            Some("render") => Self::parse_export(args, true),
+            Some("trace") => Self::parse_trace(args),
+            Some("verify") => no_arguments(args, Command::Verify),
+            Some("list") => no_arguments(args, Command::List),
+            Some(command) => Err(anyhow!("Unknown command \"{command}\"\n\n{USAGE}")),
+            None => Err(anyhow!(USAGE)),
+        }
+    }
+
+    fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let mut positional = vec![];
+        let mut part = None;
+        let mut input = None;
+        let mut params = Params::new();
+        let mut all = false;
+        let mut record = false;
+        while let Some(arg) = args.next() {
+            if arg == ALL_FLAG {
+                all = true;
+            } else if arg == RECORD_FLAG {
+                record = true;
+            } else if let Some(value) = flag_value(PART_FLAG, &arg, &mut args)? {
+                let value = value
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|x| (1..=2).contains(x))
+                    .context(format!("Part has to be 1 or 2, got \"{value}\""))?;
+                part = Some(value);
+            } else if let Some(value) = flag_value(INPUT_FLAG, &arg, &mut args)? {
+                input = Some(PathBuf::from(value));
+            } else if let Some(value) = flag_value(PARAM_FLAG, &arg, &mut args)? {
+                params.insert(&value)?;
+            } else if arg.starts_with("--") {
+                return Err(anyhow!("Unknown flag \"{arg}\"\n\n{USAGE}"));
+            } else {
+                positional.push(arg);
+            }
+        }
+
+        if all {
+            if !positional.is_empty() || part.is_some() || input.is_some() || !params.is_empty() {
+                return Err(anyhow!("{ALL_FLAG} can not be combined with a day, part, input or parameter\n\n{USAGE}"));
+            }
+            return Ok(Command::RunAll { record });
+        }
+        if record && (input.is_some() || !params.is_empty()) {
+            return Err(anyhow!("{RECORD_FLAG} only saves the answers of the default input\n\n{USAGE}"));
+        }
+        let [year, day] = positional.as_slice() else {
+            return Err(anyhow!("Expected a year and a day\n\n{USAGE}"));
+        };
+        Ok(Command::Run {
+            year: year.parse().context(format!("Wrong year \"{year}\""))?,
+            day: day.parse().context(format!("Wrong day \"{day}\""))?,
+            part,
+            input,
+            params,
+            record,
+        })
+    }
+
+    /// Arguments of `dot`, or of `render` which also takes the scale and the recording settings.
+    fn parse_export(mut args: impl Iterator<Item = String>, render: bool) -> Result<Self> {
+        let mut positional = vec![];
+        let mut input = None;
+        let mut params = Params::new();
+        let mut output = None;
+        let mut scale = DEFAULT_SCALE;
+        let defaults = Recording::default();
+        let (mut fps, mut every, mut max_frames) = (defaults.fps(), defaults.every(), defaults.max_frames());
+        while let Some(arg) = args.next() {
+            let mut render_flag = |flag| if render { flag_value(flag, &arg, &mut args) } else { Ok(None) };
+            if let Some(value) = render_flag(SCALE_FLAG)? {
+                scale = positive(SCALE_FLAG, &value)?;
+            } else if let Some(value) = render_flag(FPS_FLAG)? {
+                fps = positive(FPS_FLAG, &value)?;
+            } else if let Some(value) = render_flag(EVERY_FLAG)? {
+                every = positive(EVERY_FLAG, &value)?;
+            } else if let Some(value) = render_flag(MAX_FRAMES_FLAG)? {
+                max_frames = positive(MAX_FRAMES_FLAG, &value)?;
+            } else if let Some(value) = flag_value(INPUT_FLAG, &arg, &mut args)? {
+                input = Some(PathBuf::from(value));
+            } else if let Some(value) = flag_value(PARAM_FLAG, &arg, &mut args)? {
+                params.insert(&value)?;
+            } else if let Some(value) = flag_value(OUTPUT_FLAG, &arg, &mut args)? {
+                output = Some(PathBuf::from(value));
+            } else if arg.starts_with("--") {
+                return Err(anyhow!("Unknown flag \"{arg}\"\n\n{USAGE}"));
+            } else {
+                positional.push(arg);
+            }
+        }
+        let [year, day] = positional.as_slice() else {
+            return Err(anyhow!("Expected a year and a day\n\n{USAGE}"));
+        };
+        let year = year.parse().context(format!("Wrong year \"{year}\""))?;
+        let day = day.parse().context(format!("Wrong day \"{day}\""))?;
+        Ok(match render {
+            true => {
+                let recording = Recording::new(fps, every, max_frames)?;
+                Command::Render { year, day, input, params, output, scale, recording }
+            }
+            false => Command::Dot { year, day, input, params, output },
+        })
+    }
+
+    fn parse_trace(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let mut positional = vec![];
+        let mut input = None;
+        let mut params = Params::new();
+        while let Some(arg) = args.next() {
+            if let Some(value) = flag_value(INPUT_FLAG, &arg, &mut args)? {
+                input = Some(PathBuf::from(value));
+            } else if let Some(value) = flag_value(PARAM_FLAG, &arg, &mut args)? {
+                params.insert(&value)?;
+            } else if arg.starts_with("--") {
+                return Err(anyhow!("Unknown flag \"{arg}\"\n\n{USAGE}"));
+            } else {
+                positional.push(arg);
+            }
+        }
+        let [year, day] = positional.as_slice() else {
+            return Err(anyhow!("Expected a year and a day\n\n{USAGE}"));
+        };
+        Ok(Command::Trace {
+            year: year.parse().context(format!("Wrong year \"{year}\""))?,
+            day: day.parse().context(format!("Wrong day \"{day}\""))?,
+            input,
+            params,
+        })
+    }
+
+    pub fn execute(self) -> Result<()> {
+        match self {
+            Command::Run { year, day, part, input, params, record } => {
+                let puzzle = days::find(year, day).context(format!("Day {day} of {year} is not solved"))?;
+                let path = resolve_input(year, day, input);
+                if record && path != input_path(year, day) {
+                    return Err(anyhow!("{RECORD_FLAG} only saves the answers of {}", input_path(year, day).display()));
+                }
+                let answers =
+                    run_puzzle(puzzle, &mut open_path(&path)?, part, &params).map_err(|x| in_file(x, &path))?;
+                if record {
+                    let mut recorded = Answers::load(year)?;
+                    record_answers(&mut recorded, puzzle, answers);
+                    recorded.save(year)?;
+                }
+                Ok(())
+            }
+            Command::Dot { year, day, input, params, output } => {
+                let (puzzle, parsed) = parse_day(year, day, input, &params)?;
+                let output = output.unwrap_or_else(|| dot_path(year, day));
+                puzzle.dot(parsed.as_ref())?.save(&output)?;
+                println!("Graph written to {}", output.display());
+                Ok(())
+            }
+            Command::Render { year, day, input, params, output, scale, recording } => {
+                let (puzzle, parsed) = parse_day(year, day, input, &params)?;
+                let output = output.unwrap_or_else(|| render_path(year, day));
+                let renderer = Renderer::new(output, scale)?.with_recording(recording);
+                for path in puzzle.render(parsed.as_ref(), &renderer)? {
+                    println!("Written {}", path.display());
+                }
+                Ok(())
+            }
+            Command::Trace { year, day, input, params } => {
+                let (puzzle, parsed) = parse_day(year, day, input, &params)?;
+                print!("{}", puzzle.trace(parsed.as_ref())?);
+                Ok(())
+            }
+            Command::RunAll { record } => run_all(record),
+            Command::Verify => verify(),
+            Command::List => {
+                list();
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Entry point of the binary of one day: runs it like `aoc run <year> <day>` with the arguments
+/// of the binary, so `cargo run --bin 07 -- --input PATH` works too.
+pub fn run_day(year: u16, day: u8) -> Result<()> {
+    let run = ["run".to_string(), year.to_string(), day.to_string()];
+    Command::parse(run.into_iter().chain(env::args().skip(1)))?.execute()
+}
+
+fn no_arguments(mut args: impl Iterator<Item = String>, command: Command) -> Result<Command> {
+    match args.next() {
+        None => Ok(command),
+        Some(arg) => Err(anyhow!("Unexpected argument \"{arg}\"\n\n{USAGE}")),
+    }
+}
+
+/// Number of `--flag N` that has to be above zero.
+fn positive<T: FromStr + Default + PartialOrd>(flag: &str, value: &str) -> Result<T> {
+    let number = value.parse::<T>().ok().filter(|x| *x > T::default());
+    number.context(format!("{flag} has to be a positive number, got \"{value}\""))
+}
+
+/// Value of `--flag VALUE` or `--flag=VALUE`, `None` if `arg` is another argument.
+fn flag_value(flag: &str, arg: &str, args: &mut impl Iterator<Item = String>) -> Result<Option<String>> {
+    if arg == flag {
+        return args.next().map(Some).context(format!("{flag} needs a value"));
+    }
+    Ok(arg.strip_prefix(flag).and_then(|x| x.strip_prefix('=')).map(|x| x.to_string()))
+}
+
+/// Parsed input of a day for the commands that export it, `input` falls back to `resolve_input`.
+fn parse_day(year: u16, day: u8, input: Option<PathBuf>, params: &Params) -> Result<(&'static Puzzle, Box<dyn Any>)> {
+    let puzzle = days::find(year, day).context(format!("Day {day} of {year} is not solved"))?;
+    let path = resolve_input(year, day, input);
+    let parsed = puzzle.parse_with(&mut open_path(&path)?, params).map_err(|x| in_file(x, &path))?;
+    Ok((puzzle, parsed))
+}
+
+/// `output/<year>/<day>.dot`, generated files are kept out of `input/`.
+fn dot_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("output/{year}/{day:02}.dot"))
+}
+
+fn render_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("output/{year}/{day:02}"))
+}
+
+fn record_answers(recorded: &mut Answers, puzzle: &Puzzle, answers: Vec<(u8, Answer)>) {
+    for (part, answer) in answers {
+        recorded.set(puzzle.day, part, answer.to_string());
+    }
+}
+
+/// Runs every day that has an input file and passes its answers to `check`. Days without an input
+/// file are skipped, a failing day does not stop the others. Returns the names of failed days.
+fn run_each(mut check: impl FnMut(&Puzzle, Vec<(u8, Answer)>) -> Result<()>) -> Vec<String> {
+    let mut failed = vec![];
+    for puzzle in PUZZLES {
+        let path = input_path(puzzle.year, puzzle.day);
+        if !path.exists() {
+            println!("Skip {} - Day {:02}: no input file {}", puzzle.year, puzzle.day, path.display());
+            continue;
+        }
+        let result = open_path(&path)
+            .and_then(|mut x| run_puzzle(puzzle, &mut x, None, &Params::new()).map_err(|x| in_file(x, &path)))
+            .and_then(|x| check(puzzle, x));
+        if let Err(error) = result {
+            println!("Error: {error:#}");
+            failed.push(format!("{}-{:02}", puzzle.year, puzzle.day));
+        }
+        println!();
+    }
+    failed
+}
+
+fn run_all(record: bool) -> Result<()> {
+    let mut recorded = load_recorded()?;
+    let mut changed = BTreeSet::new();
+    let failed = run_each(|puzzle, answers| {
+        if record {
+            record_answers(recorded.get_mut(&puzzle.year).unwrap(), puzzle, answers);
+            changed.insert(puzzle.year);
+        }
+        Ok(())
+    });
+    for year in changed {
+        recorded[&year].save(year)?;
+    }
+    if !failed.is_empty() {
+        return Err(anyhow!("Failed days: {}", failed.join(", ")));
+    }
+    Ok(())
+}
+
+/// Recorded answers of every year with a registered day.
+fn load_recorded() -> Result<BTreeMap<u16, Answers>> {
+    let years = PUZZLES.iter().map(|x| x.year).collect::<BTreeSet<_>>();
+    years.into_iter().map(|year| Ok((year, Answers::load(year)?))).collect()
+}
+
+/// Days without a recorded answer are reported but do not fail the verification.
+fn verify() -> Result<()> {
+    let recorded = load_recorded()?;
+    let failed = run_each(|puzzle, answers| {
+        let mut mismatches = vec![];
+        for (part, answer) in answers {
+            let answer = answer.to_string();
+            match recorded[&puzzle.year].get(puzzle.day, part) {
+                Some(expected) if expected == answer => println!("Part {part} matches the recorded answer"),
+                Some(expected) => mismatches.push(format!("part {part} is {answer}, recorded {expected}")),
+                None => println!("Part {part} has no recorded answer"),
+            }
+        }
+        if !mismatches.is_empty() {
+            return Err(anyhow!("Mismatch: {}", mismatches.join(", ")));
+        }
+        Ok(())
+    });
+    if !failed.is_empty() {
+        return Err(anyhow!("Answers changed for: {}", failed.join(", ")));
+    }
+    Ok(())
+}
+
+fn list() {
+    for puzzle in PUZZLES {
+        let parts = if puzzle.has_part2 { "1, 2" } else { "1" };
+        let path = input_path(puzzle.year, puzzle.day);
+        let input = if path.exists() { path.display().to_string() } else { "no input".to_string() };
+        println!("{} {:02}  parts {:<4}  {}", puzzle.year, puzzle.day, parts, input);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command> {
+        Command::parse(args.split_whitespace().map(|x| x.to_string()))
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(
+            Command::Run { year: 2024, day: 7, part: None, input: None, params: Params::new(), record: false },
+            parse("run 2024 07").unwrap()
+        );
+        assert_eq!(
+            Command::Run {
+                year: 2025,
+                day: 11,
+                part: Some(2),
+                input: Some(PathBuf::from("-")),
+                params: Params::new(),
+                record: false
+            },
+            parse("run --part 2 2025 11 --input=-").unwrap()
+        );
+        assert_eq!(
+            Command::Run {
+                year: 2024,
+                day: 18,
+                part: None,
+                input: None,
+                params: Params::new().with("size", "7").with("bytes", "12"),
+                record: false
+            },
+            parse("run 2024 18 --param size=7 --param=bytes=12").unwrap()
+        );
+        assert_eq!(
+            Command::Run { year: 2025, day: 11, part: None, input: None, params: Params::new(), record: true },
+            parse("run 2025 11 --record").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_dot() {
+        assert_eq!(
+            Command::Dot { year: 2024, day: 23, input: None, params: Params::new(), output: Some(PathBuf::from("lan.dot")) },
+            parse("dot 2024 23 --output lan.dot").unwrap()
+        );
+        assert!(parse("dot 2024 23 --part 1").is_err());
+        assert!(parse("dot 2024 23 --scale 2").is_err());
+        assert_eq!(
+            Command::Render {
+                year: 2024,
+                day: 16,
+                input: None,
+                params: Params::new(),
+                output: None,
+                scale: 8,
+                recording: Recording::default()
+            },
+            parse("render 2024 16 --scale=8").unwrap()
+        );
+        assert_eq!(
+            Command::Render {
+                year: 2024,
+                day: 15,
+                input: None,
+                params: Params::new(),
+                output: Some(PathBuf::from("gifs")),
+                scale: 4,
+                recording: Recording::new(25, 10, 50).unwrap()
+            },
+            parse("render 2024 15 --every 10 --fps 25 --output gifs --max-frames=50").unwrap()
+        );
+        assert!(parse("render 2024 16 --scale 0").is_err());
+        assert!(parse("render 2024 16 --fps 200").is_err());
+        assert!(parse("render 2024 16 --every x").is_err());
+        assert!(parse("dot 2024").is_err());
+    }
+
+    #[test]
+    fn test_parse_trace() {
+        assert_eq!(
+            Command::Trace { year: 2024, day: 17, input: Some(PathBuf::from("-")), params: Params::new() },
+            parse("trace 2024 17 --input -").unwrap()
+        );
+        assert!(parse("trace 2024 17 --output listing.txt").is_err());
+        assert!(parse("trace 2024").is_err());
+    }
+
+    #[test]
+    fn test_parse_run_all_and_list() {
+        assert_eq!(Command::RunAll { record: false }, parse("run --all").unwrap());
+        assert_eq!(Command::RunAll { record: true }, parse("run --all --record").unwrap());
+        assert_eq!(Command::Verify, parse("verify").unwrap());
+        assert_eq!(Command::List, parse("list").unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("run 2024").is_err());
+        assert!(parse("run 2024 7 --part 3").is_err());
+        assert!(parse("run --all 2024 7").is_err());
+        assert!(parse("run 2024 7 --input").is_err());
+        assert!(parse("run 2024 7 --input other.txt --record").is_err());
+        assert!(parse("run 2024 14 --param width=11 --record").is_err());
+        assert!(parse("run --all --param width=11").is_err());
+        assert!(parse("run 2024 14 --param width").is_err());
+        assert!(parse("list 2024").is_err());
+    }
+}
@@ -0,0 +1,343 @@
+use crate::parse::{parse_lines, parse_number, ParseError};
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+
+/// Operations of the 3-bit computer of 2024/17, in the order of their opcodes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 8] =
+        [Opcode::Adv, Opcode::Bxl, Opcode::Bst, Opcode::Jnz, Opcode::Bxc, Opcode::Out, Opcode::Bdv, Opcode::Cdv];
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    /// Whether the operand is a combo operand. The others take a literal, `bxc` ignores it.
+    pub fn has_combo_operand(self) -> bool {
+        matches!(self, Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv)
+    }
+}
+
+impl TryFrom<u8> for Opcode {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Opcode::ALL.get(value as usize).copied().context(format!("Unknown opcode {value}"))
+    }
+}
+
+/// Name of a combo operand: `0` to `3` are literals, `4` to `6` the registers, `7` is reserved.
+pub fn combo_name(operand: u8) -> String {
+    match operand {
+        0..=3 => operand.to_string(),
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        _ => format!("reserved({operand})"),
+    }
+}
+
+/// Opcode with its operand, displayed as `adv A`, `bxl 3` or `bxc`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.opcode {
+            Opcode::Bxc => write!(f, "{}", self.opcode.mnemonic()),
+            opcode if opcode.has_combo_operand() => write!(f, "{} {}", opcode.mnemonic(), combo_name(self.operand)),
+            opcode => write!(f, "{} {}", opcode.mnemonic(), self.operand),
+        }
+    }
+}
+
+/// Listing of `program` with one `address: instruction` line per instruction. A trailing opcode
+/// without an operand is listed as a raw value.
+pub fn disassemble(program: &[u8]) -> Result<String> {
+    let mut listing = String::new();
+    for (i, pair) in program.chunks(2).enumerate() {
+        let line = match pair {
+            [opcode, operand] => Instruction { opcode: Opcode::try_from(*opcode)?, operand: *operand }.to_string(),
+            _ => format!(".byte {}", pair[0]),
+        };
+        listing.push_str(&format!("{:>3}: {line}\n", i * 2));
+    }
+    Ok(listing)
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "A={} B={} C={}", self.a, self.b, self.c)
+    }
+}
+
+/// Executed instruction with the registers after it, as recorded by [`Computer::trace`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub registers: Registers,
+    pub output: Option<u8>,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>3}: {:<7} {}", self.ip, self.instruction.to_string(), self.registers)?;
+        if let Some(output) = self.output {
+            write!(f, " out {output}")?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+/// The 3-bit computer of 2024/17. It halts when the instruction pointer leaves the program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Computer {
+    pub registers: Registers,
+    program: Vec<u8>,
+    ip: usize,
+    output: Vec<u8>,
+    steps: usize,
+}
+
+impl Computer {
+    /// Fails if a value of the program does not fit in 3 bits.
+    pub fn new(registers: Registers, program: Vec<u8>) -> Result<Self> {
+        if let Some(value) = program.iter().find(|x| **x > 7) {
+            return Err(anyhow!("{value} is not a 3-bit value"));
+        }
+        Ok(Self { registers, program, ip: 0, output: vec![], steps: 0 })
+    }
+
+    /// Reads `Register A: 729` lines for every register and a `Program: 0,1,5,4,3,0` line.
+    pub fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut registers = [None; 3];
+        let mut program = vec![];
+        parse_lines(reader, |line| {
+            let Some((name, value)) = line.split_once(": ") else {
+                return match line.trim() {
+                    "" => Ok(()),
+                    _ => Err(ParseError::expected(1, "a register or the program", line).into()),
+                };
+            };
+            let mut column = name.len() + 3;
+            match name.strip_prefix("Register ") {
+                Some(register @ ("A" | "B" | "C")) => {
+                    registers[(register.as_bytes()[0] - b'A') as usize] = Some(parse_number(value, column)?);
+                }
+                None if name == "Program" => {
+                    for part in value.split(',') {
+                        match parse_number::<u8>(part, column)? {
+                            x if x < 8 => program.push(x),
+                            _ => return Err(ParseError::expected(column, "a 3-bit value", part).into()),
+                        }
+                        column += part.len() + 1;
+                    }
+                }
+                _ => return Err(ParseError::expected(1, "Register A, B, C or Program", name).into()),
+            }
+            Ok(())
+        })?;
+        let [a, b, c] = registers;
+        let registers = Registers {
+            a: a.context("No register A")?,
+            b: b.context("No register B")?,
+            c: c.context("No register C")?,
+        };
+        Self::new(registers, program)
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Instructions executed since the start.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Restarts the program from the beginning with `registers` and no output.
+    pub fn reset(&mut self, registers: Registers) {
+        self.registers = registers;
+        self.ip = 0;
+        self.output.clear();
+        self.steps = 0;
+    }
+
+    /// Instruction at the instruction pointer, `None` once halted.
+    pub fn instruction(&self) -> Result<Option<Instruction>> {
+        match self.program.get(self.ip..self.ip + 2) {
+            Some(&[opcode, operand]) => Ok(Some(Instruction { opcode: Opcode::try_from(opcode)?, operand })),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.ip + 1 >= self.program.len()
+    }
+
+    fn combo(&self, operand: u8) -> Result<u64> {
+        match operand {
+            0..=3 => Ok(operand as u64),
+            4 => Ok(self.registers.a),
+            5 => Ok(self.registers.b),
+            6 => Ok(self.registers.c),
+            _ => Err(anyhow!("Reserved combo operand {operand} at {}", self.ip)),
+        }
+    }
+
+    /// `A / 2^power`, which is `0` for powers beyond the width of the register.
+    fn divide(&self, power: u64) -> u64 {
+        u32::try_from(power).ok().and_then(|x| self.registers.a.checked_shr(x)).unwrap_or(0)
+    }
+
+    /// Executes one instruction, `None` if the computer is halted.
+    pub fn step(&mut self) -> Result<Option<Step>> {
+        let Some(instruction) = self.instruction()? else {
+            return Ok(None);
+        };
+        let ip = self.ip;
+        let operand = instruction.operand;
+        let mut output = None;
+        let mut next = ip + 2;
+        match instruction.opcode {
+            Opcode::Adv => self.registers.a = self.divide(self.combo(operand)?),
+            Opcode::Bxl => self.registers.b ^= operand as u64,
+            Opcode::Bst => self.registers.b = self.combo(operand)? % 8,
+            Opcode::Jnz if self.registers.a != 0 => next = operand as usize,
+            Opcode::Jnz => {}
+            Opcode::Bxc => self.registers.b ^= self.registers.c,
+            Opcode::Out => output = Some((self.combo(operand)? % 8) as u8),
+            Opcode::Bdv => self.registers.b = self.divide(self.combo(operand)?),
+            Opcode::Cdv => self.registers.c = self.divide(self.combo(operand)?),
+        }
+        self.ip = next;
+        self.output.extend(output);
+        self.steps += 1;
+        Ok(Some(Step { ip, instruction, registers: self.registers, output }))
+    }
+
+    /// Runs until the computer halts and returns the whole output. Fails once `limit` instructions
+    /// have been executed since the start, so a program that loops forever does not hang.
+    pub fn run(&mut self, limit: usize) -> Result<&[u8]> {
+        while !self.is_halted() {
+            self.check_limit(limit)?;
+            self.step()?;
+        }
+        Ok(&self.output)
+    }
+
+    /// Like [`Computer::run`], recording every executed instruction.
+    pub fn trace(&mut self, limit: usize) -> Result<Vec<Step>> {
+        let mut steps = vec![];
+        while !self.is_halted() {
+            self.check_limit(limit)?;
+            steps.extend(self.step()?);
+        }
+        Ok(steps)
+    }
+
+    fn check_limit(&self, limit: usize) -> Result<()> {
+        if self.steps >= limit {
+            return Err(anyhow!("Execution limit of {limit} steps reached at {}", self.ip));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(a: u64, b: u64, c: u64, program: &[u8]) -> (Registers, Vec<u8>) {
+        let mut computer = Computer::new(Registers { a, b, c }, program.to_vec()).unwrap();
+        let output = computer.run(1000).unwrap().to_vec();
+        (computer.registers, output)
+    }
+
+    #[test]
+    fn test_instructions() {
+        assert_eq!(1, run(0, 0, 9, &[2, 6]).0.b);
+        assert_eq!(vec![0, 1, 2], run(10, 0, 0, &[5, 0, 5, 1, 5, 4]).1);
+        assert_eq!((0, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]), {
+            let (registers, output) = run(2024, 0, 0, &[0, 1, 5, 4, 3, 0]);
+            (registers.a, output)
+        });
+        assert_eq!(26, run(0, 29, 0, &[1, 7]).0.b);
+        assert_eq!(44354, run(0, 2024, 43690, &[4, 0]).0.b);
+        assert_eq!(0, run(u64::MAX, 0, 0, &[6, 4]).0.b);
+    }
+
+    #[test]
+    fn test_parse_and_disassemble() {
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+        let mut computer = Computer::parse(input.as_bytes()).unwrap();
+        assert_eq!(Registers { a: 729, b: 0, c: 0 }, computer.registers);
+        assert_eq!("  0: adv 1\n  2: out A\n  4: jnz 0\n", disassemble(computer.program()).unwrap());
+        assert_eq!(vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0], computer.run(1000).unwrap());
+
+        let error = Computer::parse("Register A: 1\nProgram: 0,9".as_bytes()).unwrap_err();
+        assert_eq!("2:12: expected a 3-bit value, found '9'", error.to_string());
+        let error = Computer::parse("Register A: 1\nRegister C: 0\nProgram: 0".as_bytes()).unwrap_err();
+        assert_eq!("No register B", error.to_string());
+    }
+
+    #[test]
+    fn test_trace_and_limit() {
+        let mut computer = Computer::new(Registers { a: 2, ..Registers::default() }, vec![0, 1, 5, 4, 3, 0]).unwrap();
+        let steps = computer.trace(100).unwrap();
+        assert_eq!(6, steps.len());
+        assert_eq!("  2: out A   A=1 B=0 C=0 out 1", steps[1].to_string());
+        assert_eq!("  4: jnz 0   A=0 B=0 C=0", steps[5].to_string());
+
+        computer.reset(Registers { a: 1, ..Registers::default() });
+        assert_eq!(vec![0], computer.run(100).unwrap());
+
+        let mut computer = Computer::new(Registers { a: 1, ..Registers::default() }, vec![3, 0]).unwrap();
+        let error = computer.run(50).unwrap_err();
+        assert_eq!("Execution limit of 50 steps reached at 0", error.to_string());
+        assert_eq!(50, computer.steps());
+
+        let mut computer = Computer::new(Registers::default(), vec![5, 7]).unwrap();
+        assert_eq!("Reserved combo operand 7 at 0", computer.step().unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::Puzzle;
+
+pub mod year2024;
+pub mod year2025;
+
+/// Every implemented day. build.rs fails the build if a day module is missing here.
+pub static PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<year2024::day01::Day01>(),
+    Puzzle::new::<year2024::day02::Day02>(),
+    Puzzle::new::<year2024::day03::Day03>(),
+    Puzzle::new::<year2024::day04::Day04>(),
+    Puzzle::new::<year2024::day05::Day05>(),
+    Puzzle::new::<year2024::day06::Day06>(),
+    Puzzle::new::<year2024::day07::Day07>(),
+    Puzzle::new::<year2024::day08::Day08>(),
+    Puzzle::new::<year2024::day09::Day09>(),
+    Puzzle::new::<year2024::day10::Day10>(),
+    Puzzle::new::<year2024::day11::Day11>(),
+    Puzzle::new::<year2024::day12::Day12>(),
+    Puzzle::new::<year2024::day13::Day13>(),
+    Puzzle::new::<year2024::day14::Day14>(),
+    Puzzle::new::<year2024::day15::Day15>(),
+    Puzzle::new::<year2024::day16::Day16>(),
+    Puzzle::new::<year2024::day17::Day17>(),
+    Puzzle::new::<year2024::day18::Day18>(),
+    Puzzle::new::<year2024::day19::Day19>(),
+    Puzzle::new::<year2024::day20::Day20>(),
+    Puzzle::new::<year2024::day21::Day21>(),
+    Puzzle::new::<year2024::day22::Day22>(),
+    Puzzle::new::<year2024::day23::Day23>(),
+    Puzzle::new::<year2024::day24::Day24>(),
+    Puzzle::new::<year2024::day25::Day25>(),
+    Puzzle::new::<year2025::day01::Day01>(),
+    Puzzle::new::<year2025::day02::Day02>(),
+    Puzzle::new::<year2025::day03::Day03>(),
+    Puzzle::new::<year2025::day04::Day04>(),
+    Puzzle::new::<year2025::day05::Day05>(),
+    Puzzle::new::<year2025::day06::Day06>(),
+    Puzzle::new::<year2025::day07::Day07>(),
+    Puzzle::new::<year2025::day08::Day08>(),
+    Puzzle::new::<year2025::day09::Day09>(),
+    Puzzle::new::<year2025::day11::Day11>(),
+    Puzzle::new::<year2025::day12::Day12>(),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|x| x.year == year && x.day == day)
+}
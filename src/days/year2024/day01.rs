@@ -0,0 +1,51 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::parse::{parse_lines, parse_pair};
+
+use std::collections::{HashMap};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    /// Both location lists, in input order.
+    type Input = (Vec<i32>, Vec<i32>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let pairs = parse_lines(reader, |x| Ok(parse_pair::<i32>(x, "   ", 1)?))?;
+        Ok(pairs.into_iter().unzip())
+    }
+
+    fn part1((first_list, second_list): &Self::Input) -> Result<usize> {
+        let mut first_list = first_list.clone();
+        let mut second_list = second_list.clone();
+        first_list.sort();
+        second_list.sort();
+        let answer = first_list.iter()
+            .zip(second_list.iter())
+            .map(|x| (*x.0 - *x.1).unsigned_abs() as usize)
+            .sum();
+        Ok(answer)
+    }
+
+    fn part2((first_list, second_list): &Self::Input) -> Result<usize> {
+        let values_counter= second_list
+            .iter()
+            .fold(HashMap::new(), |mut acc, item| {
+                *acc.entry(*item).or_insert(0) += 1;
+                acc
+            });
+
+        let answer = first_list
+            .iter()
+            .map(|x| (*x * *values_counter.get(x).unwrap_or(&0)) as usize)
+            .sum();
+
+        Ok(answer)
+    }
+}
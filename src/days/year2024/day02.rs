@@ -0,0 +1,91 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::parse::{parse_lines, parse_numbers};
+
+fn to_vec(s: &str) -> Result<Vec<usize>> {
+    Ok(parse_numbers(s, " ", 1)?)
+}
+
+fn is_increasing<'a>(data: impl Iterator<Item = &'a usize>) -> bool {
+    let mut prev: Option<usize> = None;
+    for value in data {
+        match prev  {
+            Some(prev) if prev >= *value || (*value as i32 - prev as i32) > 3 => {
+                return false;
+            },
+            _ => {}
+        }
+        prev = Some(*value);
+    }
+    true
+}
+
+fn is_safe(data: &[usize]) -> bool {
+    is_increasing(data.iter()) || is_increasing(data.iter().rev())
+}
+
+fn is_partially_increasing<'a>(data: impl Iterator<Item = &'a usize>) -> bool {
+    let mut first_vec = Vec::new();
+    let mut second_vec = Vec::new();
+    let mut prev: Option<usize> = None;
+    let mut has_one_error = false;
+
+    for value in data {
+        if has_one_error {
+            first_vec.push(*value);
+            second_vec.push(*value);
+            continue;
+        }
+
+        match prev  {
+            Some(prev) if prev >= *value || (*value as i32 - prev as i32) > 3 => {
+                has_one_error = true;
+                second_vec.pop();
+                second_vec.push(*value);
+            },
+            _ => {
+                first_vec.push(*value);
+                second_vec.push(*value);
+            }
+        }
+        prev = Some(*value);
+    }
+
+    is_safe(&first_vec) || is_safe(&second_vec)
+}
+
+fn is_partially_safe(data: &[usize]) -> bool {
+    is_partially_increasing(data.iter()) || is_partially_increasing(data.iter().rev())
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<usize>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_lines(reader, to_vec)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<usize> {
+        let answer = reports
+            .iter()
+            .filter(|x| is_safe(x))
+            .count();
+        Ok(answer)
+    }
+
+    fn part2(reports: &Self::Input) -> Result<usize> {
+        let answer = reports
+            .iter()
+            .filter(|x| is_partially_safe(x))
+            .count();
+        Ok(answer)
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::parse::read_lines;
+
+fn try_mul(s: &str, offset: usize) -> Result<(usize, usize)> {
+    if !s[offset..].starts_with("mul(") {
+        return Err(anyhow!("doesn't start"));
+    }
+    let comma = s[(offset + 4)..] .find(",").ok_or(anyhow!("not found"))?;
+    let first_val = s[(offset + 4)..(offset + 4 + comma)].parse::<usize>().map_err(|_| anyhow!("can not parse"))?;
+
+    let end = s[(offset + 4 + comma)..] .find(")").ok_or(anyhow!("can find close bracket"))?;
+    let second_val = s[(offset + 4 + comma + 1)..(offset + 4 + comma + end)].parse::<usize>().map_err(|_| anyhow!("Can not parse"))?;
+    Ok((first_val * second_val, 1))
+}
+
+fn is_do(s: &str, offset: usize) -> bool {
+    s[offset..].starts_with("do()")
+}
+
+fn is_do_not(s: &str, offset: usize) -> bool {
+    s[offset..].starts_with("don't()")
+}
+
+fn handle_string(s: &str) -> usize {
+    let mut i = 0usize;
+    let mut result = 0usize;
+    while i < s.len() {
+        let (value, offset) = try_mul(s, i).unwrap_or((0, 1));
+        result += value;
+        i += offset;
+    }
+    result
+}
+
+fn handle_string_2(s: &str, status: bool) -> (usize, bool) {
+    let mut i = 0usize;
+    let mut result = 0usize;
+    let mut multipliable = status;
+    while i < s.len() {
+        if is_do(s, i) {
+            multipliable = true;
+        }
+        if is_do_not(s, i) {
+            multipliable = false;
+        }
+        if multipliable {
+            let (value, _) = try_mul(s, i).unwrap_or((0, 1));
+            result += value;
+        }
+        i += 1;
+    }
+    (result, multipliable)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        read_lines(reader)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<usize> {
+        let answer = lines.iter().map(|x| handle_string(x)).sum();
+        Ok(answer)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<usize> {
+        let mut answer = 0usize;
+        let mut multipliable = true;
+        for line in lines {
+            let (val, status) = handle_string_2(line, multipliable);
+            answer += val;
+            multipliable = status;
+        }
+        Ok(answer)
+    }
+}
@@ -0,0 +1,52 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::{Grid, Solution};
+use crate::maze::{Bounds, Offset, Point, ALL_AROUND};
+
+fn count_xmas_word(matrix: &Grid<char>, point: Point) -> usize {
+    let bounds = Bounds::of(matrix);
+    ALL_AROUND
+        .into_iter()
+        .filter(|offset| bounds.ray(point, *offset).take(4).map(|x| matrix[x]).eq(['X', 'M', 'A', 'S']))
+        .count()
+}
+
+/// "MAS" written along `offset` with "A" at `point`.
+fn is_mas_diagonal(matrix: &Grid<char>, point: Point, offset: Offset) -> bool {
+    let bounds = Bounds::of(matrix);
+    match bounds.step(point, (-offset.0, -offset.1)) {
+        Some(start) => bounds.ray(start, offset).take(3).map(|x| matrix[x]).eq(['M', 'A', 'S']),
+        None => false,
+    }
+}
+
+fn is_cross_word(matrix: &Grid<char>, point: Point) -> bool {
+    (
+        is_mas_diagonal(matrix, point, (1, 1)) || is_mas_diagonal(matrix, point, (-1, -1))
+    ) && (
+        is_mas_diagonal(matrix, point, (-1, 1)) || is_mas_diagonal(matrix, point, (1, -1))
+    )
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse_chars(reader)
+    }
+
+    fn part1(matrix: &Self::Input) -> Result<usize> {
+        Ok(matrix.points().map(|x| count_xmas_word(matrix, x)).sum())
+    }
+
+    fn part2(matrix: &Self::Input) -> Result<usize> {
+        Ok(matrix.points().filter(|x| is_cross_word(matrix, *x)).count())
+    }
+}
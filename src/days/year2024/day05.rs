@@ -0,0 +1,70 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::{Graph, Solution};
+use crate::parse::{parse_numbers, parse_pair};
+use crate::sections::Sections;
+
+use itertools::Itertools;
+
+fn get_sequence(s: &str, rules: &mut Graph<usize>) -> Result<Vec<usize>> {
+    Ok(parse_numbers(s, ",", 1)?.into_iter().map(|x| rules.add_node(x)).collect())
+}
+
+fn is_valid_sequence(sequence: &[usize], rules: &Graph<usize>) -> bool {
+    sequence.iter().tuple_combinations().all(|(before, after)| !rules.has_edge(*after, *before))
+}
+
+/// Page in the middle of `sequence` once it is put in the order of the rules.
+fn sorted_middle_page(sequence: &[usize], rules: &Graph<usize>) -> Result<usize> {
+    let pages = rules.subgraph(sequence);
+    let order = pages.topological_sort()?;
+    Ok(*pages.node(order[order.len() / 2]))
+}
+
+/// Page ordering rules (page -> pages that must come after it) and the updates to check. Updates
+/// hold the node ids of their pages in `rules`.
+pub struct Manual {
+    rules: Graph<usize>,
+    sequences: Vec<Vec<usize>>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input = Manual;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut sections = Sections::read(reader)?;
+        let mut rules = Graph::new();
+        for (before, after) in sections.expect("rules")?.parse_lines(|x| Ok(parse_pair(x, "|", 1)?))? {
+            rules.add_edge(before, after);
+        }
+        let sequences = sections.expect("updates")?.parse_lines(|x| get_sequence(x, &mut rules))?;
+        Ok(Manual { rules, sequences })
+    }
+
+    fn part1(manual: &Self::Input) -> Result<usize> {
+        let answer = manual.sequences
+            .iter()
+            .filter(|sequence| is_valid_sequence(sequence, &manual.rules))
+            .map(|sequence| *manual.rules.node(sequence[sequence.len() / 2]))
+            .sum();
+
+        Ok(answer)
+    }
+
+    fn part2(manual: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        for sequence in manual.sequences.iter() {
+            if !is_valid_sequence(sequence, &manual.rules) {
+                answer += sorted_middle_page(sequence, &manual.rules)?;
+            }
+        }
+        Ok(answer)
+    }
+}
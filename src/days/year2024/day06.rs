@@ -0,0 +1,145 @@
+use core::result::Result::Ok;
+use anyhow::*;
+use std::io::BufRead;
+use crate::{Grid, Solution};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use crate::render::{Overlay, Palette, Renderer, GRAY, GREEN, ORANGE, WHITE};
+
+type Position = (usize, usize);
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+fn next_direction(direction: &Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Right,
+        Direction::Right => Direction::Down,
+        Direction::Down => Direction::Left,
+        Direction::Left => Direction::Up,
+    }
+}
+
+fn next_step(direction: &Direction) -> (isize, isize) {
+    match direction {
+        Direction::Up => (-1, 0),
+        Direction::Right => (0, 1),
+        Direction::Down => (1, 0),
+        Direction::Left => (0, -1),
+    }
+}
+
+fn find_guard(grid: &Grid<char>) -> Result<Position> {
+    grid.find(&'^').context("Start not found")
+}
+
+/// The guard steps forward, or turns right in place if the next cell is blocked. Fails once the
+/// guard walks off the map.
+fn move_forward(guard: &Position, direction: &Direction, grid: &Grid<char>, obstacle: Option<Position>) -> Result<(Position, Direction)> {
+    let next = grid.offset(*guard, next_step(direction)).context("Left the map")?;
+    if obstacle == Some(next) || grid[next] == '#' {
+        return Ok((*guard, next_direction(direction)));
+    }
+    Ok((next, *direction))
+}
+
+fn try_found_loop(start_point: &Position, obstacle: &Position, grid: &Grid<char>) -> Result<()> {
+    if start_point == obstacle {
+        return Err(anyhow!("Can't block start point"));
+    }
+    let mut visited = HashSet::new();
+    let mut guard = *start_point;
+    let mut direction = Direction::Up;
+
+    while let Ok((next_guard, next_direction)) = move_forward(&guard, &direction, grid, Some(*obstacle)) {
+        if visited.contains(&(next_guard, next_direction)) {
+            return Ok(())
+        }
+        visited.insert((next_guard, next_direction));
+        guard = next_guard;
+        direction = next_direction;
+    }
+
+    Err(anyhow!("Can't find loop"))
+}
+
+fn get_visited_points(start: &Position, grid: &Grid<char>) -> HashSet<Position> {
+    let mut guard = *start;
+    let mut direction = Direction::Up;
+    let mut visited = HashSet::new();
+    visited.insert(guard);
+
+    while let Ok((next_guard,next_direction)) = move_forward(&guard, &direction, grid, None) {
+        guard = next_guard;
+        direction = next_direction;
+        visited.insert(next_guard);
+    }
+
+    visited
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse_chars(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        let guard = find_guard(grid)?;
+        let visited = get_visited_points(&guard, grid);
+        Ok(visited.len())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        let guard = find_guard(grid)?;
+        let visited = get_visited_points(&guard, grid);
+        let mut obstacle_count = 0;
+
+        for point in visited {
+            if try_found_loop(&guard, &point, grid).is_ok() {
+                obstacle_count += 1
+            }
+        }
+
+        Ok(obstacle_count)
+    }
+
+    /// The lab with the cells the guard patrols in orange, and the guard walking them.
+    fn render(grid: &Self::Input, renderer: &Renderer) -> Result<Vec<PathBuf>> {
+        let start = find_guard(grid)?;
+        let palette = Palette::new(WHITE).with('#', GRAY);
+        let draw = |visited: &[Position], guard: Position| {
+            let overlay = Overlay::new().with(visited.iter().copied(), ORANGE).with([guard], GREEN);
+            renderer.draw(grid, &palette, Some(&overlay))
+        };
+        let visited = get_visited_points(&start, grid);
+        let image = renderer.save(&draw(&visited.into_iter().collect::<Vec<_>>(), start), "patrol")?;
+
+        let mut animation = renderer.animation();
+        let mut visited = vec![start];
+        let (mut guard, mut direction) = (start, Direction::Up);
+        animation.record(|| draw(&visited, guard));
+        while let Ok((next_guard, next_direction)) = move_forward(&guard, &direction, grid, None) {
+            if next_guard != guard {
+                visited.push(next_guard);
+            }
+            (guard, direction) = (next_guard, next_direction);
+            animation.record(|| draw(&visited, guard));
+        }
+        animation.finish(|| draw(&visited, guard));
+        Ok(vec![image, renderer.save_animation(&animation, "patrol")?])
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::parse::{parse_lines, parse_number, parse_numbers, ParseError};
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Add,
+    Mul,
+    Concat,
+}
+
+impl Operator {
+    /// `None` on overflow, which never gives the test value.
+    fn apply(self, a: usize, b: usize) -> Option<usize> {
+        match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Mul => a.checked_mul(b),
+            Operator::Concat => a.checked_mul(10usize.checked_pow(b.checked_ilog10().unwrap_or(0) + 1)?)?.checked_add(b),
+        }
+    }
+}
+
+fn try_parse_string(s: &str) -> Result<(usize, Vec<usize>)> {
+    let (value, numbers) = s.split_once(": ").ok_or_else(|| ParseError::expected(1, "a test value followed by ': '", s))?;
+    Ok((parse_number(value, 1)?, parse_numbers(numbers, " ", value.len() + 3)?))
+}
+
+/// Whether `operators` put between `numbers` and evaluated left to right can give `value`.
+fn can_build_equation(value: usize, numbers: &[usize], operators: &[Operator]) -> bool {
+    let Some((first, rest)) = numbers.split_first() else {
+        return false;
+    };
+    let n = operators.len();
+    (0..n.pow(rest.len() as u32)).any(|i| {
+        let result = rest
+            .iter()
+            .enumerate()
+            .try_fold(*first, |acc, (j, number)| operators[i / n.pow(j as u32) % n].apply(acc, *number));
+        result == Some(value)
+    })
+}
+
+/// Sum of the test values that `operators` can build.
+fn calibration(equations: &[(usize, Vec<usize>)], operators: &[Operator]) -> usize {
+    equations
+        .iter()
+        .filter(|(value, numbers)| can_build_equation(*value, numbers, operators))
+        .map(|(value, _)| value)
+        .sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    /// Test value and the numbers of every equation.
+    type Input = Vec<(usize, Vec<usize>)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_lines(reader, try_parse_string)
+    }
+
+    fn part1(equations: &Self::Input) -> Result<usize> {
+        Ok(calibration(equations, &[Operator::Add, Operator::Mul]))
+    }
+
+    fn part2(equations: &Self::Input) -> Result<usize> {
+        Ok(calibration(equations, &[Operator::Add, Operator::Mul, Operator::Concat]))
+    }
+}
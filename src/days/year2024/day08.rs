@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use anyhow::*;
+use std::io::BufRead;
+use crate::{Grid, Solution};
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Cell {
+    Antenna(char),
+    FreeCell,
+    Antinode,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let char = match self {
+            Cell::FreeCell => '.',
+            Cell::Antinode => '#',
+            Cell::Antenna(ch) => *ch,
+        };
+        write!(f, "{}", char)
+    }
+}
+
+fn read_grid<R: BufRead>(reader: R) -> Result<Grid<Cell>> {
+    Grid::parse(reader, |x| Ok(match x {
+        '.' => Cell::FreeCell,
+        '#' => Cell::Antinode,
+        ch => Cell::Antenna(ch),
+    }))
+}
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+struct Point(i32, i32);
+
+
+impl Point {
+    fn from(x: usize, y: usize) -> Self {
+        Point(x as i32, y as i32)
+    }
+
+    fn from_float(x: f64, y: f64) -> Self {
+        Point(
+            x as i32,
+            y as i32,
+        )
+    }
+
+    fn x<T: From<i32>>(&self) -> T {
+        T::from(self.0)
+    }
+
+    fn y<T: From<i32>>(&self) -> T {
+        T::from(self.1)
+    }
+
+    /// The point as a grid position, `None` if it is outside of `grid`.
+    fn position(&self, grid: &Grid<Cell>) -> Option<(usize, usize)> {
+        let point = (usize::try_from(self.0).ok()?, usize::try_from(self.1).ok()?);
+        grid.contains(point).then_some(point)
+    }
+
+    fn distance(&self, other: &Point) -> f64 {
+        ((self.x::<f64>() - other.x::<f64>()).powi(2) + (self.y::<f64>() - other.y::<f64>()).powi(2)).sqrt()
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{:})", &self.0, &self.1)
+    }
+}
+
+struct Line {
+    a: Option<f64>,
+    b: f64,
+}
+
+impl Line {
+    fn from(p1: &Point, p2: &Point) -> Self {
+        let a = if p2.x::<i32>() != p1.x::<i32>() {
+            Some((p2.y::<f64>() - p1.y::<f64>()) / (p2.x::<f64>() - p1.x::<f64>()))
+        } else {
+            None
+        };
+        let b = match a {
+            None => p1.x(),
+            Some(a) => p1.y::<f64>() - a * p1.x::<f64>()
+        };
+        Self { a, b }
+    }
+
+    fn tan_a(&self) -> f64 {
+        self.a.unwrap_or(f64::NAN)
+    }
+
+    fn sin_a(&self) -> f64 {
+        (self.tan_a().powi(2) / (self.tan_a().powi(2) + 1f64)).sqrt()
+    }
+
+    fn cos_a(&self) -> f64 {
+        (1f64 - self.sin_a().powi(2)).sqrt()
+    }
+
+    fn line_from(&self, p1: &Point, distance: f64) -> Point {
+        let (x, y) = match self.a {
+            None => {
+                (p1.x::<f64>(), p1.y::<f64>() + distance)
+            },
+            Some(a) => {
+                let x = p1.x::<f64>() + distance * self.cos_a();
+                let y = a * x + self.b;
+                (x.round(), y.round())
+            }
+        };
+        Point::from_float(x, y)
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.a {
+            None => write!(f, "y = {:.4}", self.b),
+            Some(a) => write!(f, "y = {:.4} * x + {:.4}", a, self.b)
+        }
+    }
+}
+
+
+type AntennasGroups = HashMap<Cell, HashSet<Point>>;
+fn get_antennas_groups(grid: &Grid<Cell>) -> AntennasGroups {
+    let mut antennas: HashMap<Cell, HashSet<Point>> = HashMap::new();
+    for ((i, j), cell) in grid.iter() {
+        match cell {
+            Cell::FreeCell | Cell::Antinode => {},
+            cell => {
+                let points = antennas.entry(*cell).or_default();
+                points.insert(Point::from(i, j));
+            },
+        }
+    }
+
+    antennas
+}
+
+fn get_possible_nodes(point: &Point, line: &Line, distance: f64, grid: &Grid<Cell>) -> Vec<Point> {
+    let mut result = vec![];
+    result.push(*point);
+    let mut i = 1;
+    let mut next_point = line.line_from(point, (i as f64) * distance);
+    while next_point.position(grid).is_some() {
+        result.push(next_point);
+        i += 1;
+        next_point = line.line_from(point, (i as f64) * distance);
+    }
+
+    let mut i = 1;
+    let mut next_point = line.line_from(point, -(i as f64) * distance);
+    while next_point.position(grid).is_some() {
+        result.push(next_point);
+        i += 1;
+        next_point = line.line_from(point, -(i as f64) * distance);
+    }
+
+    result
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input = Grid<Cell>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        read_grid(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        let antennas_groups = get_antennas_groups(grid);
+        let mut antinodes = HashSet::new();
+        for (_, points_set) in antennas_groups {
+            let points = points_set.iter().collect::<Vec<_>>();
+            for i in 0..points.len() {
+                for j in (i+1)..points.len() {
+                    let distance = points[i].distance(points[j]);
+                    let line = Line::from(points[i], points[j]);
+                    let possible_nodes = [
+                        line.line_from(points[i], distance),
+                        line.line_from(points[i], -distance),
+                        line.line_from(points[j], distance),
+                        line.line_from(points[j], -distance),
+                    ];
+                    for point in possible_nodes {
+                        if !points_set.contains(&point) && point.position(grid).is_some() {
+                            antinodes.insert(point);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(antinodes.len())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        let antennas_groups = get_antennas_groups(grid);
+        let mut antinodes = HashSet::new();
+        for (_, points_set) in antennas_groups {
+            let points = points_set.iter().collect::<Vec<_>>();
+            for i in 0..points.len() {
+                for j in (i+1)..points.len() {
+                    let distance = points[i].distance(points[j]);
+                    let line = Line::from(points[i], points[j]);
+                    let possible_nodes = get_possible_nodes(points[i], &line, distance, grid);
+
+                    for point in possible_nodes {
+                        antinodes.insert(point);
+                    }
+                }
+            }
+        }
+        Ok(antinodes.len())
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt::{Display, Formatter};
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::parse::{at_line, parse_digit, ParseError};
+
+type FileId = usize;
+type Size = usize;
+type Offset = usize;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Block {
+    FreeBlock,
+    FileBlock(FileId),
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Block::FreeBlock => write!(f, "[.]"),
+            Block::FileBlock(file_id) => write!(f, "[{}]", file_id)
+        }
+    }
+}
+
+fn read_data<R: BufRead>(mut reader: R) -> Result<Vec<Block>> {
+    let mut raw_data = String::new();
+    reader.read_line(&mut raw_data)?;
+    let mut data = vec![];
+    let mut file_id: FileId = 0;
+    let line = raw_data.trim_end();
+    if line.is_empty() {
+        return Err(at_line(ParseError::expected(1, "a disk map", line).into(), 1, line));
+    }
+    for (i, ch) in line.chars().enumerate() {
+        let size = parse_digit(ch, i + 1).map_err(|x| at_line(x.into(), 1, line))?;
+        if i % 2 == 0 {
+            for _ in 0..size {
+                data.push(Block::FileBlock(file_id));
+            }
+            file_id += 1;
+        } else {
+            for _ in 0..size {
+                data.push(Block::FreeBlock);
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+fn compress_data(data: &mut [Block]) -> Result<()> {
+    let mut i = 0;
+    let Some(mut j) = data.iter().rposition(|x| *x != Block::FreeBlock) else {
+        return Ok(());
+    };
+    while i < j {
+        data[i] = match data[i] {
+            Block::FileBlock(file_id) => Block::FileBlock(file_id),
+            Block::FreeBlock => {
+                let result= match data[j] {
+                    Block::FileBlock(file_id) => Block::FileBlock(file_id),
+                    Block::FreeBlock => {
+                        return Err(anyhow!("Should not bee a free block"));
+                    }
+                };
+                data[j] = Block::FreeBlock;
+                result
+            }
+        };
+
+        while data[j] == Block::FreeBlock {
+            j -= 1;
+        }
+
+        i += 1;
+    }
+
+    Ok(())
+}
+
+fn compute_free_space(data: &[Block], offset: Offset) -> Size {
+    let mut i = 0usize;
+    while data[offset + i] == Block::FreeBlock {
+        i += 1;
+    }
+    i
+}
+
+fn compute_file_size(data: &[Block], file_id: FileId, offset: Offset) -> Size {
+    let mut j = 0;
+    while offset + j < data.len() && data[offset + j] == Block::FileBlock(file_id) {
+        j += 1;
+    }
+    j
+}
+
+fn write_file(data: &mut [Block], offset: Offset, file_id: FileId, size: Size) -> Result<Offset> {
+    for i in 0..size {
+        data[offset + i] = match data[offset + i] {
+            Block::FreeBlock => Block::FileBlock(file_id),
+            Block::FileBlock(_) => return Err(anyhow!("File corruption could not write"))
+        }
+    }
+    Ok(offset + size - 1)
+}
+
+fn erase(data: &mut [Block], offset: Offset, size: Size) {
+    for i in 0..size {
+        data[offset + i] = Block::FreeBlock;
+    }
+}
+
+fn read_files(data: &[Block]) -> Vec<(FileId, Offset, Size)> {
+    let mut result = vec![];
+    let mut i: Offset = 0;
+    while i < data.len() {
+        i = match data[i] {
+            Block::FreeBlock => i + 1,
+            Block::FileBlock(file_id) => {
+                let size = compute_file_size(data, file_id, i);
+                result.push((file_id, i, size));
+                i + size
+            }
+        }
+    }
+    result
+}
+
+fn compress_v2(data: &mut [Block]) -> Result<()> {
+    let mut files_stack = read_files(data);
+
+    while let Some((file_id, offset, size)) = files_stack.pop() {
+        let mut i: Offset = 0;
+        while i < offset {
+            i = match data[i] {
+                Block::FreeBlock => {
+                    let free_space = compute_free_space(data, i);
+                    if free_space >= size {
+                        write_file(data, i, file_id, size)?;
+                        erase(data, offset, size);
+                        break;
+                    }
+                    i + free_space
+                },
+                _ => i + 1
+            };
+        }
+    }
+
+    Ok(())
+}
+
+fn check_sum(data: &[Block]) -> usize {
+    data
+        .iter()
+        .enumerate()
+        .map(|(i, x)| match x {
+            Block::FreeBlock => 0,
+            Block::FileBlock(file_id) => file_id * i,
+        })
+        .sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Block>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        read_data(reader)
+    }
+
+    fn part1(blocks: &Self::Input) -> Result<usize> {
+        let mut blocks = blocks.clone();
+        compress_data(&mut blocks)?;
+        let answer = check_sum(&blocks);
+        Ok(answer)
+    }
+
+    fn part2(blocks: &Self::Input) -> Result<usize> {
+        let mut blocks = blocks.clone();
+        compress_v2(&mut blocks)?;
+        let answer = check_sum(&blocks);
+        Ok(answer)
+    }
+}
@@ -0,0 +1,74 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::{Grid, Solution};
+use crate::parse::parse_digit;
+use crate::maze::{Bounds, Point};
+use std::collections::{HashSet, VecDeque};
+
+fn read_grid<R: BufRead>(reader: R) -> Result<Grid<usize>> {
+    Grid::parse(reader, |x| Ok(if x =='.' { 11 } else { parse_digit(x, 0)? as usize }))
+}
+
+/// Neighbors of `point` one step higher.
+fn next_steps(grid: &Grid<usize>, point: Point) -> impl Iterator<Item = Point> + '_ {
+    Bounds::of(grid)
+        .cardinal_neighbors(point)
+        .filter(move |x| grid[point] + 1 == grid[*x])
+}
+
+fn compute_slope_1(grid: &Grid<usize>, start: Point) -> usize {
+    if grid[start] != 0 {
+        return 0
+    };
+    let mut que = VecDeque::new();
+    que.push_back(start);
+    let mut reached = HashSet::new();
+    while let Some(point) = que.pop_front() {
+        if grid[point] == 9 {
+            reached.insert(point);
+            continue;
+        }
+        que.extend(next_steps(grid, point));
+    }
+    reached.len()
+}
+
+fn compute_slope_2(grid: &Grid<usize>, start: Point) -> usize {
+    if grid[start] != 0 {
+        return 0
+    };
+    let mut que = VecDeque::new();
+    que.push_back(start);
+    let mut answer = 0;
+    while let Some(point) = que.pop_front() {
+        if grid[point] == 9 {
+            answer += 1;
+            continue;
+        }
+        que.extend(next_steps(grid, point));
+    }
+    answer
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Input = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        read_grid(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        Ok(grid.points().map(|x| compute_slope_1(grid, x)).sum())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        Ok(grid.points().map(|x| compute_slope_2(grid, x)).sum())
+    }
+}
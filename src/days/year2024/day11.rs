@@ -0,0 +1,77 @@
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
+use crate::Solution;
+
+fn read_vector<R: BufRead>(mut reader: R) -> Result<Vec<usize>> {
+    let mut str = String::new();
+    reader.read_line(&mut str)?;
+    let mut result = vec![];
+    for s in str.split_whitespace() {
+        result.push(usize::from_str(s)?);
+    }
+    Ok(result)
+}
+
+fn compute_next_stones(stone: usize) -> Vec<usize> {
+    if stone == 0 {
+        return vec![1usize];
+    }
+    let log_value = stone.ilog10() + 1;
+    if log_value.is_multiple_of(2) {
+        let dividor = 10usize.pow(log_value / 2) ;
+        return vec![stone / dividor, stone % dividor];
+    }
+    vec![stone * 2024]
+}
+
+fn compute_from_stone(stone: usize, step: usize, limit: usize, cache: &mut HashMap<(usize, usize), usize>) -> usize {
+    if limit == step {
+        return 1;
+    }
+    let diff = limit - step;
+    if cache.contains_key(&(stone, diff)) {
+        return *cache.get(&(stone, diff)).unwrap();
+    }
+    let mut result = 0usize;
+    for next_stone in compute_next_stones(stone) {
+        result += compute_from_stone(next_stone, step + 1, limit, cache);
+    }
+    cache.insert((stone, diff), result);
+    result
+}
+
+fn compute_part(stones: &[usize], count: usize) -> usize {
+    let mut cache: HashMap<(usize, usize), usize> = HashMap::new();
+
+    let mut result = 0usize;
+    for stone in stones {
+        result += compute_from_stone(*stone, 0, count, &mut cache);
+    }
+
+    result
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        read_vector(reader)
+    }
+
+    fn part1(stones: &Self::Input) -> Result<usize> {
+        Ok(compute_part(stones, 25))
+    }
+
+    fn part2(stones: &Self::Input) -> Result<usize> {
+        Ok(compute_part(stones, 75))
+    }
+}
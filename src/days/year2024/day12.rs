@@ -0,0 +1,177 @@
+use core::result::Result::Ok;
+use anyhow::*;
+use std::io::BufRead;
+use crate::{Grid, Solution};
+use crate::maze::{Bounds, Offset, Point};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+enum Side {
+    VerticalLeft,
+    VerticalRight,
+    HorizontalTop,
+    HorizontalBottom,
+}
+
+const MOVES: [(Side, Offset); 4] = [
+    (Side::VerticalRight, (0, 1)),
+    (Side::HorizontalBottom, (1, 0)),
+    (Side::HorizontalTop, (-1, 0)),
+    (Side::VerticalLeft, (0, -1))
+];
+
+/// Neighbor of `point` at `offset` if it grows the same plant.
+fn same_plant(grid: &Grid<char>, point: Point, offset: Offset) -> Option<Point> {
+    Bounds::of(grid).step(point, offset).filter(|x| grid[*x] == grid[point])
+}
+
+fn compute_perimeter(x: usize, y: usize, grid: &Grid<char>) -> usize {
+    MOVES
+        .iter()
+        .filter(|(_, offset)| same_plant(grid, (x, y), *offset).is_none())
+        .count()
+}
+
+fn get_sides(x: usize, y: usize, grid: &Grid<char>) -> Vec<Side> {
+    MOVES
+        .iter()
+        .filter(|(_, offset)| same_plant(grid, (x, y), *offset).is_none())
+        .map(|(side, _)| *side)
+        .collect::<Vec<_>>()
+}
+
+fn count_sequences(values: &[usize]) -> usize {
+    let mut values = values.to_vec();
+    values.sort();
+    let mut counter = 0;
+    let mut prev = None;
+    for value in values {
+        prev = match prev {
+            Some(x) if x + 1 == value  => Some(value),
+            _ => {
+                counter += 1;
+                Some(value)
+            }
+        };
+    }
+    counter
+}
+
+fn count_horizontal(coords: &[(usize, usize)]) -> usize {
+    coords
+        .iter()
+        .fold(
+            HashMap::new(),
+            |mut acc, x| {
+                let entry = acc.entry(x.0).or_insert(vec![]);
+                entry.push(x.1);
+                acc
+            }
+        )
+        .values()
+        .map(|x| count_sequences(x))
+        .sum()
+}
+
+fn count_vertical(coords: &[(usize, usize)]) -> usize {
+    coords
+        .iter()
+        .fold(
+            HashMap::new(),
+            |mut acc, x| {
+                let entry = acc.entry(x.1).or_insert(vec![]);
+                entry.push(x.0);
+                acc
+            }
+        )
+        .values()
+        .map(|x| count_sequences(x))
+        .sum()
+}
+
+
+fn count_side(side: &Side, coords: &[(usize, usize)]) -> usize {
+    match side {
+        Side::HorizontalTop | Side::HorizontalBottom => count_horizontal(coords),
+        Side::VerticalLeft | Side::VerticalRight => count_vertical(coords)
+    }
+}
+fn count_all_sides(sides: &HashMap<Side, Vec<(usize, usize)>>) -> usize {
+    sides.iter().map(|(side, coords)| count_side(side, coords)).sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse_chars(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        let n = grid.height();
+        let m  = grid.width();
+        let mut visited = HashSet::new();
+        let mut result = 0;
+        for i in 0..n {
+            for j in 0..m {
+                if visited.contains(&(i,j)) {
+                    continue;
+                }
+                let mut que = VecDeque::new();
+                que.push_back((i, j));
+                let mut area = 0usize;
+                let mut perimeter = 0;
+                while let Some((x, y)) = que.pop_front() {
+                    if visited.contains(&(x, y)) {
+                        continue;
+                    }
+                    area += 1;
+                    perimeter += compute_perimeter(x, y, grid);
+                    que.extend(MOVES.iter().filter_map(|(_, offset)| same_plant(grid, (x, y), *offset)));
+                    visited.insert((x, y));
+                }
+                result += area * perimeter;
+            }
+        }
+        Ok(result)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        let n = grid.height();
+        let m  = grid.width();
+        let mut visited = HashSet::new();
+        let mut result = 0;
+        for i in 0..n {
+            for j in 0..m {
+                if visited.contains(&(i,j)) {
+                    continue;
+                }
+                let mut que = VecDeque::new();
+                que.push_back((i, j));
+                let mut area = 0usize;
+                let mut sides = HashMap::new();
+                while let Some((x, y)) = que.pop_front() {
+                    if visited.contains(&(x, y)) {
+                        continue;
+                    }
+                    area += 1;
+                    for side in get_sides(x, y, grid) {
+                        let entry = sides.entry(side).or_insert(vec![]);
+                        entry.push((x, y));
+                    }
+                    que.extend(MOVES.iter().filter_map(|(_, offset)| same_plant(grid, (x, y), *offset)));
+                    visited.insert((x, y));
+                }
+                result += area * count_all_sides(&sides);
+            }
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::parse::{parse_number, ParseError};
+use crate::sections::{Section, Sections};
+
+
+type Vector = (usize, usize);
+type Point = (usize, usize);
+
+type InputValue = (Vector, Vector, Point);
+
+/// `Button A: X+94, Y+34` or `Prize: X=8400, Y=5400`.
+fn parse_coordinates(line: &str) -> Result<(usize, usize)> {
+    let (label, coordinates) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::expected(1, "a button or a prize", line))?;
+    let column = label.len() + 3;
+    let (x, y) = coordinates
+        .split_once(", ")
+        .ok_or_else(|| ParseError::expected(column, "X and Y separated by ', '", coordinates))?;
+    Ok((parse_coordinate(x, column)?, parse_coordinate(y, column + x.len() + 2)?))
+}
+
+/// `X+94` or `Y=5400` at `column`.
+fn parse_coordinate(text: &str, column: usize) -> Result<usize, ParseError> {
+    let value = text
+        .get(2..)
+        .filter(|_| text.starts_with(['X', 'Y']))
+        .ok_or_else(|| ParseError::expected(column, "X or Y and a value", text))?;
+    parse_number(value, column + 2)
+}
+
+fn parse_input_value(section: &Section) -> Result<InputValue> {
+    match section.parse_lines(parse_coordinates)?[..] {
+        [a, b, prize] => Ok((a, b, prize)),
+        _ => Err(anyhow!("{section}, starting at line {}: expected two buttons and a prize", section.first_line)),
+    }
+}
+
+fn compute_det(a: Point, b: Point) -> i128 {
+    (a.0 * b.1) as i128  - (a.1 * b.0) as i128
+}
+
+fn find_min_tokens(a: Vector, b: Vector, goal: Point) -> usize {
+    let det = compute_det(a, b);
+    if det == 0 {
+        // Collinear buttons would need the cheaper of many solutions, the input has none.
+        return 0
+    }
+    let det_a = compute_det(goal, b);
+    let det_b = compute_det(a, goal);
+    if det_a % det == 0 && det_b % det == 0 {
+        (det_a / det * 3 + det_b / det) as usize
+    } else {
+        0
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type Input = Vec<InputValue>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Sections::read(reader)?.map(|x| parse_input_value(&x)).collect()
+    }
+
+    fn part1(machines: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        for input in machines {
+            let min_tokens = find_min_tokens(input.0, input.1, input.2);
+            answer += min_tokens;
+        }
+        Ok(answer)
+    }
+
+    fn part2(machines: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        for input in machines {
+            let goal = input.2;
+            let goal = (goal.0 + 10000000000000, goal.1 + 10000000000000);
+            let min_tokens = find_min_tokens(input.0, input.1, goal);
+            answer += min_tokens;
+        }
+        Ok(answer)
+    }
+}
@@ -0,0 +1,130 @@
+use anyhow::*;
+use std::io::BufRead;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use crate::{Params, Solution};
+use crate::render::{point_grid, Palette, Renderer, GREEN, WHITE};
+use crate::parse::{parse_lines, parse_pair, ParseError};
+
+type Point = (i32, i32);
+type Vector = (i32, i32);
+type RobotInfo = (Point, Vector);
+
+/// `p=x,y` or `v=x,y` starting at `column`.
+fn read_point(s: &str, column: usize) -> Result<Point, ParseError> {
+    match s.split_once('=') {
+        Some((name, point)) if name.len() == 1 => parse_pair(point, ",", column + 2),
+        _ => Err(ParseError::expected(column, "p=x,y or v=x,y", s)),
+    }
+}
+
+fn read_robot_info(s: &str) -> Result<RobotInfo>{
+    let (position, velocity) = s.split_once(' ').ok_or_else(|| ParseError::expected(1, "a position and a velocity", s))?;
+    Ok((read_point(position, 1)?, read_point(velocity, position.len() + 2)?))
+}
+
+fn read_data<R: BufRead>(reader: R) -> Result<Vec<RobotInfo>> {
+    parse_lines(reader, read_robot_info)
+}
+
+/// Robots of a room, 101x103 in the real puzzle and 11x7 in the example.
+pub struct Room {
+    robots: Vec<RobotInfo>,
+    width: i32,
+    height: i32,
+}
+
+fn positions(robots: &[RobotInfo], seconds: i32, width: i32, height: i32) -> Vec<Point> {
+    robots.iter().map(|(point, velocity)| {
+        (
+            ((((point.0 + velocity.0 * seconds) % width) + width) % width),
+            ((((point.1 + velocity.1 * seconds) % height) + height) % height),
+        )
+    }).collect::<Vec<_>>()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type Input = Room;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Self::parse_with(reader, &Params::new())
+    }
+
+    fn parse_with<R: BufRead>(reader: R, params: &Params) -> Result<Self::Input> {
+        params.expect_only(&["width", "height"])?;
+        let width = params.get("width", 101)?;
+        let height = params.get("height", 103)?;
+        if width <= 0 || height <= 0 {
+            return Err(anyhow!("The room has to be at least 1x1, got {width}x{height}"));
+        }
+        Ok(Room { robots: read_data(reader)?, width, height })
+    }
+
+    fn part1(room: &Self::Input) -> Result<usize> {
+        let Room { ref robots, width, height } = *room;
+        let mut q1 = 0;
+        let mut q2 = 0;
+        let mut q3 = 0;
+        let mut q4 = 0;
+
+        for point in positions(robots, 100, width, height) {
+            match (point.0 - width / 2, point.1 - height / 2) {
+                (x, y) if x < 0 && y < 0 => {
+                    q1 += 1;
+                },
+                (x, y) if x > 0 && y < 0 => {
+                    q2 += 1;
+                },
+                (x, y) if x < 0 && y > 0 => {
+                    q3 += 1;
+                },
+                (x, y) if x > 0 && y > 0 => {
+                    q4 += 1;
+                },
+                _ => {},
+            }
+        }
+        Ok(q1 * q2 * q3 * q4)
+    }
+
+    /// The tree shows up on the first second when no two robots share a tile.
+    fn part2(room: &Self::Input) -> Result<usize> {
+        let Room { ref robots, width, height } = *room;
+        for i in 0..width * height {
+            let points = positions(robots, i, width, height);
+            if points.iter().collect::<HashSet<_>>().len() == points.len() {
+                return Ok(i as usize);
+            }
+        }
+        Err(anyhow!("Robots never spread over distinct tiles"))
+    }
+
+    /// The room after 100 seconds and when the tree shows up, and the robots moving until then.
+    fn render(room: &Self::Input, renderer: &Renderer) -> Result<Vec<PathBuf>> {
+        let Room { ref robots, width, height } = *room;
+        let palette = Palette::new(WHITE).with(true, GREEN);
+        let draw = |seconds| {
+            let points = positions(robots, seconds, width, height).into_iter().map(|(x, y)| (y as usize, x as usize));
+            renderer.draw(&point_grid(height as usize, width as usize, points), &palette, None)
+        };
+        let tree = Self::part2(room)? as i32;
+        let mut paths = vec![renderer.save(&draw(100), "100")?, renderer.save(&draw(tree), "tree")?];
+        let mut animation = renderer.animation();
+        for seconds in 0..=tree {
+            if animation.is_full() {
+                break;
+            }
+            animation.record(|| draw(seconds));
+        }
+        animation.finish(|| draw(tree));
+        paths.push(renderer.save_animation(&animation, "robots")?);
+        Ok(paths)
+    }
+}
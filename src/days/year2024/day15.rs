@@ -0,0 +1,410 @@
+use core::result::Result::Ok;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use anyhow::*;
+use std::io::BufRead;
+use std::path::PathBuf;
+use crate::{Grid, Solution};
+use crate::render::{Palette, Renderer, GRAY, ORANGE, RED, WHITE};
+use crate::parse::ParseError;
+use crate::sections::{Section, Sections};
+
+/// Chars of the warehouse map.
+const CELLS: &str = "'#', '@', 'O' or '.'";
+
+trait FromChar {
+    fn from_char(ch: char) -> Result<Vec<Self>> where Self: Sized;
+}
+
+trait IsRobot {
+    fn is_robot(&self) -> bool;
+}
+
+trait IsComputable {
+    fn is_computable(&self) -> bool;
+}
+
+trait GetFree {
+    fn get_free() -> Self where Self: Sized;
+}
+
+trait CommonCell:  FromChar + IsRobot + Display + Clone + Copy + PartialEq + IsComputable + GetFree {}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Cell {
+    Free,
+    Wall,
+    Box,
+    Robot
+}
+
+impl CommonCell for Cell {}
+
+impl FromChar for Cell {
+    fn from_char(ch: char) -> Result<Vec<Self>> {
+        match ch {
+            '#' => Ok(vec![Self::Wall]),
+            '@' => Ok(vec![Self::Robot]),
+            'O' => Ok(vec![Self::Box]),
+            '.' => Ok(vec![Self::Free]),
+            _ => Err(ParseError::expected(0, CELLS, ch).into()),
+        }
+    }
+}
+
+impl IsRobot for Cell {
+    fn is_robot(&self) -> bool {
+        *self == Cell::Robot
+    }
+}
+
+impl IsComputable for Cell {
+    fn is_computable(&self) -> bool {
+        *self == Cell::Box
+    }
+}
+
+impl GetFree for Cell {
+    fn get_free() -> Self
+    where
+        Self: Sized
+    {
+        Self::Free
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cell::Free => write!(f, "."),
+            Cell::Wall => write!(f, "#"),
+            Cell::Box => write!(f, "O"),
+            Cell::Robot => write!(f, "@")
+        }
+    }
+}
+
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Cell2 {
+    Free,
+    Wall,
+    LeftBox,
+    RightBox,
+    Robot,
+}
+
+impl CommonCell for Cell2 {}
+
+impl FromChar for Cell2 {
+    fn from_char(ch: char) -> Result<Vec<Self>> {
+        match ch {
+            '#' => Ok(vec![Self::Wall, Self::Wall]),
+            '@' => Ok(vec![Self::Robot, Self::Free]),
+            'O' => Ok(vec![Self::LeftBox, Self::RightBox]),
+            '.' => Ok(vec![Self::Free, Self::Free]),
+            _ => Err(ParseError::expected(0, CELLS, ch).into()),
+        }
+    }
+}
+
+impl IsRobot for Cell2 {
+    fn is_robot(&self) -> bool {
+        *self == Cell2::Robot
+    }
+}
+
+impl IsComputable for Cell2 {
+    fn is_computable(&self) -> bool {
+        *self == Cell2::LeftBox
+    }
+}
+
+impl GetFree for Cell2 {
+    fn get_free() -> Self
+    where
+        Self: Sized
+    {
+       Cell2::Free
+    }
+}
+
+impl Display for Cell2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cell2::LeftBox => write!(f, "["),
+            Cell2::RightBox => write!(f, "]"),
+            Cell2::Free => write!(f, "."),
+            Cell2::Wall => write!(f, "#"),
+            Cell2::Robot => write!(f, "@"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Move {
+    Left,
+    Right,
+    Up,
+    Down
+}
+
+impl Move {
+    /// Move of `ch` at `column`.
+    fn try_from(ch: char, column: usize) -> Result<Self, ParseError> {
+        match ch {
+            '<' => Ok(Move::Left),
+            '>' => Ok(Move::Right),
+            '^' => Ok(Move::Up),
+            'v' => Ok(Move::Down),
+            _ => Err(ParseError::expected(column, "<, >, ^ or v", ch)),
+        }
+    }
+
+    fn delta(&self) -> (i32, i32) {
+        match self {
+            Move::Left => (0, -1),
+            Move::Right => (0, 1),
+            Move::Up => (-1, 0),
+            Move::Down => (1, 0)
+        }
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Move::Left => write!(f, "Left"),
+            Move::Right => write!(f, "Right"),
+            Move::Up => write!(f, "Up"),
+            Move::Down => write!(f, "Down")
+
+        }
+    }
+}
+
+/// The map with every char turned into its cells, two per char in the wide warehouse.
+fn read_grid<T: CommonCell>(map: &Section) -> Result<Grid<T>> {
+    let cells = map.parse_grid(T::from_char)?;
+    Grid::from_rows(cells.rows().map(|row| row.concat()).collect::<Vec<_>>())
+}
+
+fn robot<T: CommonCell>(grid: &Grid<T>) -> Option<(usize, usize)> {
+    grid.iter().find(|(_, x)| x.is_robot()).map(|(pos, _)| pos)
+}
+
+fn get<T: CommonCell>(grid: &Grid<T>, pos: (usize, usize)) -> Result<T> {
+    grid.get(pos).copied().context("No such cell")
+}
+
+fn move_cell<T: CommonCell>(grid: &mut Grid<T>, coord: (usize, usize), movement: &Move) -> Result<(usize, usize)> {
+    let new_pos = compute_next_position(&coord, movement)?;
+    let cell = get(grid, coord)?;
+    *grid.get_mut(new_pos).context("No such cell")? = cell;
+    grid[coord] = T::get_free();
+    Ok(new_pos)
+}
+
+fn get_movements(line: &str) -> Result<Vec<Move>> {
+    Ok(line.chars().enumerate().map(|(j, ch)| Move::try_from(ch, j + 1)).collect::<Result<Vec<_>, _>>()?)
+}
+
+fn move_robot(robot: (usize, usize), movement: Move, grid: &mut Grid<Cell>) -> Result<(usize, usize)> {
+    let mut stack = vec![];
+    let mut pos = robot;
+    while get(grid, pos)? == Cell::Robot || get(grid, pos)? == Cell::Box {
+        stack.push(pos);
+        pos = compute_next_position(&pos, &movement)?
+    }
+
+    match get(grid, pos)? {
+        Cell::Wall => Ok(robot),
+        Cell::Free => {
+            let mut pos = robot;
+            while !stack.is_empty() {
+                pos = move_cell(grid, stack.pop().unwrap(), &movement)?;
+            }
+            Ok(pos)
+        },
+        _ => Err(anyhow!("Unexpected behavior"))
+    }
+}
+
+fn compute_next_position(pos: &(usize, usize), movement: &Move) -> Result<(usize, usize)> {
+    let delta = movement.delta();
+    Ok((
+        usize::try_from(pos.0 as i32 + delta.0).context("can not parse")?,
+        usize::try_from(pos.1 as i32 + delta.1).context("can not parse")?
+    ))
+}
+
+fn is_free(positions: &[(usize, usize)], grid: &Grid<Cell2>) -> Result<bool> {
+    if positions.is_empty() {
+        return Ok(true);
+    }
+    let cells =
+        positions
+            .iter()
+            .map(
+                |x| get(grid, *x)
+            )
+            .collect::<Result<Vec<_>>>()?;
+    Ok(cells.iter().all(|x| *x == Cell2::Free))
+}
+
+fn has_wall(positions: &[(usize, usize)], grid: &Grid<Cell2>) -> Result<bool> {
+    if positions.is_empty() {
+        return Ok(false);
+    }
+    let cells =
+        positions
+            .iter()
+            .map(
+                |x| get(grid, *x)
+            )
+            .collect::<Result<Vec<_>>>()?;
+    Ok(cells.contains(&Cell2::Wall))
+}
+
+fn get_new_positions(positions: &Vec<(usize, usize)>, movement: &Move, grid: &Grid<Cell2>) -> Result<Vec<(usize, usize)>>{
+    match movement {
+        Move::Left | Move::Right => positions.iter().map(|x| {
+            compute_next_position(x, movement)
+        })
+            .collect::<Result<Vec<_>>>(),
+        _ => {
+            let mut next_positions = HashSet::new();
+            for pos in positions {
+                let next_pos = compute_next_position(pos, movement)?;
+                match get(grid, next_pos)? {
+                    Cell2::Wall => {
+                        next_positions.insert(next_pos);
+                    },
+                    Cell2::LeftBox => {
+                        next_positions.insert((next_pos.0, next_pos.1 + 1));
+                        next_positions.insert(next_pos);
+                    },
+                    Cell2::RightBox => {
+                        next_positions.insert((next_pos.0, next_pos.1 - 1));
+                        next_positions.insert(next_pos);
+                    },
+                    _ => {},
+                }
+            }
+            Ok(next_positions.into_iter().collect::<Vec<(usize, usize)>>())
+        }
+    }
+}
+
+fn move_robot_2(robot: (usize, usize), movement: Move, grid: &mut Grid<Cell2>) -> Result<(usize, usize)> {
+    let mut stack = vec![];
+    let mut positions = vec![robot];
+    while !is_free(&positions, grid)? && !has_wall(&positions, grid)? {
+        stack.push(positions.clone());
+        positions = get_new_positions(&positions, &movement, grid)?;
+    }
+    let mut next_pos = robot;
+    if is_free(&positions, grid)? {
+        while let Some(positions) = stack.pop() {
+            for pos in positions {
+                next_pos = move_cell(grid, pos, &movement)?;
+            }
+        }
+    }
+    Ok(next_pos)
+}
+
+fn compute_value<T: CommonCell>(grid: &Grid<T>) -> usize {
+    grid.iter().filter(|(_, x)| x.is_computable()).map(|((i, j), _)| i * 100 + j).sum()
+}
+
+type MoveRobot<T> = fn((usize, usize), Move, &mut Grid<T>) -> Result<(usize, usize)>;
+
+/// Runs every move of the warehouse on `grid` with `move_robot`, calling `step` with the grid
+/// before the first move and after each one. A move that fails leaves the robot where it is.
+fn run_moves<T: CommonCell>(
+    warehouse: &Warehouse,
+    grid: &mut Grid<T>,
+    move_robot: MoveRobot<T>,
+    mut step: impl FnMut(&Grid<T>) -> Result<()>,
+) -> Result<()> {
+    let mut robot = robot(grid).context("Robot not found")?;
+    step(grid)?;
+    for movement in warehouse.movements.iter() {
+        robot = match move_robot(robot, *movement, grid) {
+            Ok(pos) => pos,
+            _ => robot
+        };
+        step(grid)?;
+    }
+    Ok(())
+}
+
+/// Animation of the robot moving the boxes of `grid`, saved as `<name>.gif`.
+fn animate<T: CommonCell>(
+    warehouse: &Warehouse,
+    mut grid: Grid<T>,
+    move_robot: MoveRobot<T>,
+    palette: Palette<T>,
+    renderer: &Renderer,
+    name: &str,
+) -> Result<PathBuf> {
+    let mut animation = renderer.animation();
+    run_moves(warehouse, &mut grid, move_robot, |grid| {
+        animation.record(|| renderer.draw(grid, &palette, None));
+        Ok(())
+    })?;
+    animation.finish(|| renderer.draw(&grid, &palette, None));
+    renderer.save_animation(&animation, name)
+}
+
+/// The warehouse map, as it is and widened for part 2, and the robot's moves.
+pub struct Warehouse {
+    grid: Grid<Cell>,
+    wide_grid: Grid<Cell2>,
+    movements: Vec<Move>,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type Input = Warehouse;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut sections = Sections::read(reader)?;
+        let map = sections.expect("map")?;
+        let movements = sections.expect("moves")?.parse_lines(get_movements)?.into_iter().flatten().collect();
+        Ok(Warehouse { grid: read_grid(&map)?, wide_grid: read_grid(&map)?, movements })
+    }
+
+    fn part1(warehouse: &Self::Input) -> Result<usize> {
+        let mut grid = warehouse.grid.clone();
+        run_moves(warehouse, &mut grid, move_robot, |_| Ok(()))?;
+        Ok(compute_value(&grid))
+    }
+
+    fn part2(warehouse: &Self::Input) -> Result<usize> {
+        let mut grid = warehouse.wide_grid.clone();
+        run_moves(warehouse, &mut grid, move_robot_2, |_| Ok(()))?;
+        Ok(compute_value(&grid))
+    }
+
+    /// The robot pushing the boxes around both warehouses.
+    fn render(warehouse: &Self::Input, renderer: &Renderer) -> Result<Vec<PathBuf>> {
+        let palette = Palette::new(WHITE).with(Cell::Wall, GRAY).with(Cell::Robot, RED).with(Cell::Box, ORANGE);
+        let wide_palette = Palette::new(WHITE)
+            .with(Cell2::Wall, GRAY)
+            .with(Cell2::Robot, RED)
+            .with(Cell2::LeftBox, ORANGE)
+            .with(Cell2::RightBox, ORANGE);
+        Ok(vec![
+            animate(warehouse, warehouse.grid.clone(), move_robot, palette, renderer, "warehouse")?,
+            animate(warehouse, warehouse.wide_grid.clone(), move_robot_2, wide_palette, renderer, "wide-warehouse")?,
+        ])
+    }
+}
@@ -0,0 +1,77 @@
+use anyhow::*;
+use std::io::BufRead;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use crate::Solution;
+use crate::render::{Overlay, Palette, Renderer, GRAY, GREEN, ORANGE, WHITE};
+use crate::maze::{Point, Grid, Bounds, Direction, read_map, find_start_end_point};
+use crate::search::{dense_dijkstra, dense_dijkstra_all};
+
+type State = (Point, Direction);
+
+fn state_index(grid: &Grid, &((i, j), direction): &State) -> usize {
+    (i * grid.width() + j) * 4 + direction as usize
+}
+
+/// Moving forward costs 1 and turning in place costs 1000.
+fn moves(grid: &Grid, position: Point, direction: Direction) -> Vec<(State, usize)> {
+    let mut moves = vec![
+        ((position, direction.rotate_clockwise()), 1000),
+        ((position, direction.rotate_counterclockwise()), 1000),
+    ];
+    if let Some(next) = Bounds::of(grid).step(position, direction.offset()).filter(|x| grid[*x] != '#') {
+        moves.push(((next, direction), 1));
+    }
+    moves
+}
+
+/// Tiles on any of the cheapest paths.
+fn best_seats(grid: &Grid) -> Result<HashSet<Point>> {
+    let (start_point, _) = find_start_end_point(grid)?;
+    let paths = dense_dijkstra_all(
+        grid.height() * grid.width() * 4,
+        |state| state_index(grid, state),
+        (start_point, Direction::East),
+        |&(position, direction)| moves(grid, position, direction),
+        |&(position, _)| grid[position] == 'E',
+    );
+    Ok(paths.context("End is unreachable")?.nodes(|(position, _)| *position))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Input = Grid;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        read_map(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        let (start_point, _) = find_start_end_point(grid)?;
+        let path = dense_dijkstra(
+            grid.height() * grid.width() * 4,
+            |state| state_index(grid, state),
+            (start_point, Direction::East),
+            |&(position, direction)| moves(grid, position, direction),
+            |&(position, _)| grid[position] == 'E',
+        );
+        Ok(path.context("End is unreachable")?.cost)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        Ok(best_seats(grid)?.len())
+    }
+
+    /// The maze with the best seats in orange.
+    fn render(grid: &Self::Input, renderer: &Renderer) -> Result<Vec<PathBuf>> {
+        let palette = Palette::new(WHITE).with('#', GRAY).with('S', GREEN).with('E', GREEN);
+        let overlay = Overlay::new().with(best_seats(grid)?, ORANGE);
+        Ok(vec![renderer.save(&renderer.draw(grid, &palette, Some(&overlay)), "best-seats")?])
+    }
+}
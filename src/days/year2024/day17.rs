@@ -0,0 +1,101 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::computer::{disassemble, Computer, Registers};
+
+/// Both the example and the real programs finish in a few hundred steps.
+const STEP_LIMIT: usize = 1_000_000;
+
+/// Smallest value of register A that makes the program output itself. The program shifts A by
+/// one octal digit per output, so A is built from the last output backwards, trying the 8 values
+/// of each digit and going back when none of them fits.
+fn rev_compute(computer: &Computer) -> Result<u64> {
+    let program = computer.program().to_vec();
+    if program.is_empty() {
+        return Ok(0);
+    }
+    let mut computer = computer.clone();
+    rev_compute_from(&mut computer, &program, program.len() - 1, 0)?
+        .context("No value of register A makes the program output itself")
+}
+
+/// Smallest `a` followed by one more digit that outputs `program[i..]`, then recurses to the
+/// digits of the earlier outputs.
+fn rev_compute_from(computer: &mut Computer, program: &[u8], i: usize, a: u64) -> Result<Option<u64>> {
+    for digit in 0..8 {
+        let candidate = a << 3 | digit;
+        computer.reset(Registers { a: candidate, b: 0, c: 0 });
+        if computer.run(STEP_LIMIT)? != &program[i..] {
+            continue;
+        }
+        if i == 0 {
+            return Ok(Some(candidate));
+        }
+        if let Some(a) = rev_compute_from(computer, program, i - 1, candidate)? {
+            return Ok(Some(a));
+        }
+    }
+    Ok(None)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 17;
+
+    type Input = Computer;
+    type Part1 = Vec<u8>;
+    type Part2 = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Computer::parse(reader)
+    }
+
+    fn part1(computer: &Self::Input) -> Result<Vec<u8>> {
+        Ok(computer.clone().run(STEP_LIMIT)?.to_vec())
+    }
+
+    fn part2(computer: &Self::Input) -> Result<u64> {
+        rev_compute(computer)
+    }
+
+    /// Disassembled program, then every instruction of the part 1 run with the registers after it.
+    fn trace(computer: &Self::Input) -> Result<String> {
+        let mut listing = disassemble(computer.program())?;
+        listing.push('\n');
+        for step in computer.clone().trace(STEP_LIMIT)? {
+            listing.push_str(&format!("{step}\n"));
+        }
+        Ok(listing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace() {
+        let input = "Register A: 10\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+        let computer = Day17::parse(input.as_bytes()).unwrap();
+        let expected = "  0: adv 1
+  2: out A
+  4: jnz 0
+
+  0: adv 1   A=5 B=0 C=0
+  2: out A   A=5 B=0 C=0 out 5
+  4: jnz 0   A=5 B=0 C=0
+  0: adv 1   A=2 B=0 C=0
+  2: out A   A=2 B=0 C=0 out 2
+  4: jnz 0   A=2 B=0 C=0
+  0: adv 1   A=1 B=0 C=0
+  2: out A   A=1 B=0 C=0 out 1
+  4: jnz 0   A=1 B=0 C=0
+  0: adv 1   A=0 B=0 C=0
+  2: out A   A=0 B=0 C=0 out 0
+  4: jnz 0   A=0 B=0 C=0
+";
+        assert_eq!(expected, Day17::trace(&computer).unwrap());
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::*;
+use std::io::BufRead;
+use std::path::PathBuf;
+use crate::{Grid, Params, Solution};
+use crate::render::{point_grid, Overlay, Palette, Renderer, GRAY, RED, WHITE};
+use crate::maze::Bounds;
+use crate::parse::{parse_lines, parse_pair, ParseError};
+use crate::search::{dense_astar, Path};
+
+type Point = (usize, usize);
+
+fn read_point(s: &str, size: usize) -> Result<Point> {
+    let (x, y): Point = parse_pair(s, ",", 1)?;
+    if x.max(y) >= size {
+        return Err(ParseError::new(1, s, format!("coordinates have to be below {size}")).into());
+    }
+    Ok((x, y))
+}
+
+fn read_points<R: BufRead>(reader: R, size: usize) -> Result<Vec<Point>> {
+    parse_lines(reader, |x| read_point(x, size))
+}
+
+fn manhattan_distance((i1, j1): Point, (i2, j2): Point) -> usize {
+    i1.abs_diff(i2) + j1.abs_diff(j2)
+}
+
+/// Shortest path from the top left corner to the bottom right one around the fallen bytes.
+fn shortest_path(grid: &Grid<bool>) -> Option<Path<Point, usize>> {
+    let bounds = Bounds::of(grid);
+    let end_point = (grid.height() - 1, grid.width() - 1);
+    dense_astar(
+        grid.height() * grid.width(),
+        |(i, j)| i * bounds.width + j,
+        (0, 0),
+        |point| bounds.cardinal_neighbors(*point).filter(|x| !grid[*x]).map(|x| (x, 1)),
+        |point| manhattan_distance(*point, end_point),
+        |point| *point == end_point,
+    )
+}
+
+/// Falling bytes of a memory space, 71x71 with 1024 fallen bytes for part 1 in the real puzzle
+/// and 7x7 with 12 in the example.
+pub struct Memory {
+    points: Vec<Point>,
+    size: usize,
+    bytes: usize,
+}
+
+impl Memory {
+    /// Memory after the first `bytes` have fallen. Points are `X,Y`, so rows are `Y`.
+    fn fallen(&self, bytes: usize) -> Grid<bool> {
+        point_grid(self.size, self.size, self.points.iter().take(bytes).map(|(x, y)| (*y, *x)))
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    type Input = Memory;
+    type Part1 = usize;
+    type Part2 = (usize, usize);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Self::parse_with(reader, &Params::new())
+    }
+
+    fn parse_with<R: BufRead>(reader: R, params: &Params) -> Result<Self::Input> {
+        params.expect_only(&["size", "bytes"])?;
+        let size = params.get("size", 71)?;
+        if size == 0 {
+            return Err(anyhow!("The memory space has to be at least 1x1"));
+        }
+        Ok(Memory { points: read_points(reader, size)?, size, bytes: params.get("bytes", 1024)? })
+    }
+
+    fn part1(memory: &Self::Input) -> Result<usize> {
+        let path = shortest_path(&memory.fallen(memory.bytes)).context("No path found")?;
+        Ok(path.cost)
+    }
+
+    fn part2(memory: &Self::Input) -> Result<(usize, usize)> {
+        let mut grid = memory.fallen(memory.bytes);
+        for (x, y) in memory.points.iter().skip(memory.bytes) {
+            grid[(*y, *x)] = true;
+            if shortest_path(&grid).is_none() {
+                return Ok((*x, *y));
+            }
+        }
+        Err(anyhow!("Always reachable"))
+    }
+
+    /// Memory after the bytes of part 1 have fallen, with the shortest path in red.
+    fn render(memory: &Self::Input, renderer: &Renderer) -> Result<Vec<PathBuf>> {
+        let grid = memory.fallen(memory.bytes);
+        let path = shortest_path(&grid).context("No path found")?;
+        let overlay = Overlay::new().with(path.states, RED);
+        let palette = Palette::new(WHITE).with(true, GRAY);
+        Ok(vec![renderer.save(&renderer.draw(&grid, &palette, Some(&overlay)), "path")?])
+    }
+}
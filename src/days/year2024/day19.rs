@@ -0,0 +1,46 @@
+use anyhow::*;
+use std::io::BufRead;
+use num_bigint::BigUint;
+use crate::Solution;
+use crate::sections::Sections;
+use crate::trie::Trie;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+
+    /// Available towel patterns and the designs to build.
+    type Input = (Trie, Vec<String>);
+    type Part1 = usize;
+    type Part2 = BigUint;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut sections = Sections::read(reader)?;
+        let towels = sections.expect("towels")?.parse(|lines| match lines {
+            [line] => Ok(Trie::new(line.split(", "))),
+            _ => Err(anyhow!("Expected one line of towels, found {}", lines.len())),
+        })?;
+        let designs = sections.expect("designs")?.parse_lines(|x| Ok(x.to_string()))?;
+        Ok((towels, designs))
+    }
+
+    fn part1((towels, designs): &Self::Input) -> Result<usize> {
+        let answer = designs
+            .iter()
+            .filter(|x| towels.can_segment(x))
+            .count();
+
+        Ok(answer)
+    }
+
+    fn part2((towels, designs): &Self::Input) -> Result<BigUint> {
+        let answer = designs
+            .iter()
+            .map(|x| towels.count_segmentations(x))
+            .sum();
+
+        Ok(answer)
+    }
+}
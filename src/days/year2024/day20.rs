@@ -0,0 +1,133 @@
+use anyhow::*;
+use std::io::BufRead;
+use std::collections::HashMap;
+use crate::{Params, Solution};
+use crate::maze::{find_start_end_point, Bounds, Point};
+use crate::maze::{read_map, Direction, Grid};
+use crate::parse::{at_line, ParseError};
+use crate::search::dense_dijkstra;
+
+
+/// Cells of the track from `start_point` to `end_point`, both included.
+pub fn find_optimal(start_point: Point, end_point: Point, grid: &Grid) -> Result<Vec<Point>> {
+    let bounds = Bounds::of(grid);
+    let path = dense_dijkstra(
+        grid.height() * grid.width(),
+        |&(i, j)| i * grid.width() + j,
+        start_point,
+        |point| bounds.cardinal_neighbors(*point).filter(|x| grid[*x] != '#').map(|x| (x, 1usize)),
+        |point| *point == end_point,
+    );
+    Ok(path.context("Optimal way not found")?.states)
+}
+
+/// The track is surrounded by walls, so a cheat never leaves the map.
+fn read_track<R: BufRead>(reader: R) -> Result<Grid> {
+    let grid = read_map(reader)?;
+    let last = (grid.height().saturating_sub(1), grid.width().saturating_sub(1));
+    let on_border = |(i, j): Point| i == 0 || j == 0 || i == last.0 || j == last.1;
+    let open = grid.iter().find(|(point, ch)| on_border(*point) && **ch != '#');
+    if let Some(((i, j), ch)) = open {
+        let error = ParseError::expected(j + 1, "a wall '#' on the border", ch.to_string());
+        return Err(at_line(error.into(), i + 1, &grid.row(i).iter().collect::<String>()));
+    }
+    Ok(grid)
+}
+
+pub fn count_optimal_with_cheat(optimal_path: &[Point], bounds: Bounds, threshold: usize) -> usize {
+    let mut count = 0;
+    let point_cost = optimal_path.iter().enumerate().fold(HashMap::new(), |mut acc, x| {
+        acc.insert(*x.1, x.0);
+        acc
+    });
+    for point in optimal_path {
+        let x = point_cost[point];
+        for direction in Direction::ALL {
+            let (di, dj) = direction.offset();
+            let Some(next_point) = bounds.step(*point, (2 * di, 2 * dj)) else {
+                continue;
+            };
+            let y = match point_cost.get(&next_point) {
+                Some(value) if *value > x => *value,
+                _ => continue
+            };
+            if threshold + x + 2 <= y {
+                count += 1
+            }
+        }
+    }
+    count
+}
+
+pub fn count_optimal_with_cheat_v2(optimal_path: &[Point], bounds: Bounds, threshold: usize) -> usize {
+    let mut count = 0;
+    let point_cost = optimal_path.iter().enumerate().fold(HashMap::new(), |mut acc, x| {
+        acc.insert(*x.1, x.0);
+        acc
+    });
+    for point in optimal_path {
+        let x = point_cost[point];
+        for i in -20i32..=20 {
+            for j in -20i32..=20 {
+                if i == 0 && j == 0 {
+                    continue
+                }
+                let extra_steps = i.unsigned_abs() as usize + j.unsigned_abs() as usize;
+                if extra_steps > 20  {
+                    continue;
+                }
+                let Some(next_point) = bounds.step(*point, (i as isize, j as isize)) else {
+                    continue;
+                };
+                let y = match point_cost.get(&next_point) {
+                    Some(value) if *value > x + extra_steps => *value,
+                    _ => continue
+                };
+                if y - (x + extra_steps) >= threshold {
+                    count += 1
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Race track and the least picoseconds a cheat has to save in each part, 100 in the real
+/// puzzle. The example answers are given for smaller savings.
+pub struct Track {
+    grid: Grid,
+    save1: usize,
+    save2: usize,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+
+    type Input = Track;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Self::parse_with(reader, &Params::new())
+    }
+
+    fn parse_with<R: BufRead>(reader: R, params: &Params) -> Result<Self::Input> {
+        params.expect_only(&["save1", "save2"])?;
+        Ok(Track { grid: read_track(reader)?, save1: params.get("save1", 100)?, save2: params.get("save2", 100)? })
+    }
+
+    fn part1(track: &Self::Input) -> Result<usize> {
+        let (start_point, end_point) = find_start_end_point(&track.grid)?;
+        let optimal_path = find_optimal(start_point, end_point, &track.grid)?;
+        Ok(count_optimal_with_cheat(&optimal_path, Bounds::of(&track.grid), track.save1))
+    }
+
+    fn part2(track: &Self::Input) -> Result<usize> {
+        let (start_point, end_point) = find_start_end_point(&track.grid)?;
+        let optimal_path = find_optimal(start_point, end_point, &track.grid)?;
+        Ok(count_optimal_with_cheat_v2(&optimal_path, Bounds::of(&track.grid), track.save2))
+    }
+}
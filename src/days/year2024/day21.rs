@@ -0,0 +1,282 @@
+use core::result::Result::Ok;
+use std::collections::{HashMap, HashSet};
+use anyhow::*;
+use std::io::BufRead;
+use itertools::Itertools;
+use crate::Solution;
+use crate::parse::{at_column, parse_lines, parse_number, ParseError};
+
+const DIGITAL_PAD_SYMBOLS: [char; 11] = ['A', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+const DIRECTION_PAD_SYMBOLS: [char; 5] = ['A', '<', '>', '^', 'v'];
+
+/// Position of a key of the numeric keypad. Errors leave the column to the caller.
+fn digital_pad(symbol: char) -> Result<(i32, i32), ParseError> {
+    match symbol {
+        '7' => Ok((0, 0)),
+        '8' => Ok((0, 1)),
+        '9' => Ok((0, 2)),
+        '4' => Ok((1, 0)),
+        '5' => Ok((1, 1)),
+        '6' => Ok((1, 2)),
+        '1' => Ok((2, 0)),
+        '2' => Ok((2, 1)),
+        '3' => Ok((2, 2)),
+        '0' => Ok((3, 1)),
+        'A' => Ok((3, 2)),
+        _ => Err(ParseError::expected(0, "a key of the numeric keypad", symbol)),
+    }
+}
+
+fn validate_digital_position(position: (i32, i32)) -> bool {
+    matches!(
+        position,
+        (0, 0) | (0, 1) | (0, 2) |
+        (1, 0) | (1, 1) | (1, 2) |
+        (2, 0) | (2, 1) | (2, 2) |
+        (3, 1) | (3, 2)
+    )
+}
+
+/// Position of a key of the directional keypad. Errors leave the column to the caller.
+fn directional_pad(symbol: char) -> Result<(i32, i32), ParseError> {
+    match symbol {
+        '^' => Ok((0, 1)),
+        'A' => Ok((0, 2)),
+        '<' => Ok((1, 0)),
+        'v' => Ok((1, 1)),
+        '>' => Ok((1, 2)),
+        _ => Err(ParseError::expected(0, "a key of the directional keypad", symbol))
+    }
+}
+
+fn validate_directional_position(position: (i32, i32)) -> bool {
+    matches!(
+        position,
+        (0, 1) | (0, 2) |
+        (1, 0) | (1, 1) | (1, 2)
+    )
+}
+
+fn get_moves(dx: i32, dec_char: char, inc_char: char) -> Result<Vec<char>> {
+    let ch = match dx {
+        0 => {'.'},
+        x if x > 0 => {
+            inc_char
+        },
+        x if x < 0 => {
+            dec_char
+        },
+        _ => {
+            return Err(anyhow!("unexpected number"));
+        }
+    };
+    Ok(std::iter::repeat_n(ch, dx.unsigned_abs() as usize).collect::<Vec<_>>())
+}
+
+
+fn gen_all_variants<ValidatePosition>(
+    start_point: (i32, i32),
+    end_point: (i32, i32),
+    validate_position: ValidatePosition,
+) -> Result<Vec<String>>
+where
+    ValidatePosition: Fn((i32, i32)) -> bool,
+{
+    let dx = end_point.0 - start_point.0;
+    let dy = end_point.1 - start_point.1;
+    let moves = get_moves(dx, '^', 'v')?
+        .iter().
+        chain(
+            get_moves(dy, '<', '>')?.iter()
+        )
+        .permutations((dx.abs() + dy.abs()) as usize)
+        .filter(|x| {
+            let mut position = start_point;
+            for mv in x {
+                position = match mv {
+                    '^' => (position.0  - 1, position.1),
+                    'v' => (position.0  + 1, position.1),
+                    '<' => (position.0, position.1 - 1),
+                    '>' => (position.0, position.1 + 1),
+                    _ => (-1, -1)
+                };
+                if !validate_position(position) {
+                    return false;
+                }
+            }
+            true
+        })
+        .map(|x| format!("{}A", String::from_iter(x)))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    Ok(moves)
+}
+
+type Graph<Item> = HashMap<(char, char), Item>;
+
+fn build_graph<T, GetCost>(
+    symbols: &[char],
+    get_cost: &GetCost,
+    default: T,
+) -> Result<Graph<T>>
+    where
+        T: std::cmp::Ord + Clone,
+        GetCost: Fn(char, char) -> Result<T>,
+{
+    let mut graph: Graph<T> = HashMap::new();
+    for ch1 in symbols {
+        for ch2 in symbols {
+            if ch1 == ch2 {
+                graph.insert((*ch1, *ch2), default.clone());
+            } else {
+                graph.insert((*ch1, *ch2), get_cost(*ch1, *ch2)?);
+            }
+        }
+    }
+    Ok(graph)
+}
+
+fn get_cost_on_40(ch1: char, ch2: char) -> Result<usize> {
+   let start_point = directional_pad(ch1)?;
+   let end_point = directional_pad(ch2)?;
+    gen_all_variants(start_point, end_point, Box::new(validate_directional_position))?
+        .into_iter()
+        .map(|x| x.len())
+        .min()
+        .context(
+            format!("No moves on cost_40 from '{}' to '{}'", ch1, ch2)
+        )
+}
+
+fn compute_cost<T, Add>(code: &str, costs: &Graph<T>, initial: T, add: &Add) -> T
+    where
+        T: Clone,
+        Add: Fn(T, T) -> T
+{   let mut value: T =  initial;
+    let mut prev = '-';
+    for ch in code.chars() {
+        if prev != '-' {
+            value = add(value, costs.get(&(prev, ch)).unwrap().clone());
+        }
+        prev = ch;
+    }
+    value
+}
+
+fn get_cost_on_pad<GetPoint, ValidatePosition>(ch1: char, ch2: char, costs: &Graph<usize>, get_point: &GetPoint, validate_position: &ValidatePosition) -> Result<usize>
+    where
+        GetPoint: Fn(char) -> Result<(i32, i32), ParseError>,
+        ValidatePosition: Fn((i32, i32)) -> bool
+{
+    let start_point = get_point(ch1)?;
+    let end_point = get_point(ch2)?;
+    let variants = gen_all_variants(start_point, end_point, Box::new(validate_position))?;
+    variants
+        .iter()
+        .map(|x| {
+            let cost = compute_cost(&format!("A{x}"), costs, 0, &|a, b| a + b);
+            cost
+        })
+        .min()
+        .context(
+            format!("No moves on direction pad '{}' to '{}'", ch1, ch2)
+        )
+}
+
+/// A door code, digits followed by `A`, with its numeric part.
+fn parse_code(line: &str) -> Result<(String, usize)> {
+    for (i, ch) in line.chars().enumerate() {
+        digital_pad(ch).map_err(|x| at_column(x.into(), i + 1, &ch.to_string()))?;
+    }
+    let digits = line
+        .strip_suffix('A')
+        .filter(|x| !x.contains('A'))
+        .ok_or_else(|| ParseError::expected(1, "digits followed by A", line))?;
+    Ok((line.to_string(), parse_number(digits, 1)?))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 21;
+
+    type Input = Vec<(String, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_lines(reader, parse_code)
+    }
+
+    fn part1(codes: &Self::Input) -> Result<usize> {
+        // How much cost on normal pad to move and press symbol on 40 pad
+        let graph_40 = build_graph(
+            &DIRECTION_PAD_SYMBOLS,
+            &get_cost_on_40,
+            1,
+        )?;
+
+        // How much cost on normal pad to move and press symbol on radi pad
+        let graph_radi = build_graph(
+            &DIRECTION_PAD_SYMBOLS,
+            &|x, y| get_cost_on_pad(x, y, &graph_40, &directional_pad, &validate_directional_position),
+            1
+        )?;
+
+        let graph_pressure = build_graph(
+            &DIGITAL_PAD_SYMBOLS,
+            &|x, y| get_cost_on_pad(x, y, &graph_radi, &digital_pad, &validate_digital_position),
+            1
+        )?;
+
+        Ok(codes
+            .iter()
+            .map(|(code, value)| {
+                let num_code = compute_cost(
+                    &format!("A{code}"),
+                    &graph_pressure,
+                    0,
+                    &|x, y| x + y
+                );
+                num_code * value
+            })
+            .sum())
+    }
+
+    fn part2(codes: &Self::Input) -> Result<usize> {
+        let mut graph = build_graph(
+            &DIRECTION_PAD_SYMBOLS,
+            &get_cost_on_40,
+            1,
+        )?;
+
+        for _ in 0..24 {
+            graph = build_graph(
+                &DIRECTION_PAD_SYMBOLS,
+                &|x, y| get_cost_on_pad(x, y, &graph, &directional_pad, &validate_directional_position),
+                1
+            )?;
+        }
+
+        let graph_pressure = build_graph(
+            &DIGITAL_PAD_SYMBOLS,
+            &|x, y| get_cost_on_pad(x, y, &graph, &digital_pad, &validate_digital_position),
+            1
+        )?;
+
+        Ok(codes
+            .iter()
+            .map(|(code, value)| {
+                let num_code = compute_cost(
+                    &format!("A{code}"),
+                    &graph_pressure,
+                    0,
+                    &|x, y| x + y
+                );
+                num_code * value
+            })
+            .sum())
+    }
+}
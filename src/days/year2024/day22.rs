@@ -0,0 +1,157 @@
+use std::collections::{HashMap, VecDeque};
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::parse::{parse_lines, parse_number};
+
+#[derive(Copy, Clone)]
+struct NumberGenerator {
+    value: usize
+}
+
+impl NumberGenerator {
+    fn new(value: usize) -> Self {
+        Self {
+            value
+        }
+    }
+}
+const PRUNE_VALUE: usize = 16777216;
+
+impl Iterator for NumberGenerator {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let return_value = self.value;
+        let value = self.value << 6;
+        self.value = (value ^ self.value) % PRUNE_VALUE;
+        let value = self.value >> 5;
+        self.value = (value ^ self.value) % PRUNE_VALUE;
+        let value = (self.value << 11) % PRUNE_VALUE;
+        self.value = (value ^ self.value) % PRUNE_VALUE;
+        Some(return_value)
+    }
+}
+
+
+fn get_nth_value(initial: usize, step: usize) -> usize {
+    let gen = NumberGenerator::new(initial);
+    let mut gen = gen.skip(step);
+    gen.next().unwrap()
+}
+
+fn get_diff_generator(value: usize) -> Box<dyn Iterator<Item=isize>> {
+    let gen = NumberGenerator::new(value);
+    let delta = gen
+        .into_iter()
+        .skip(1)
+        .zip(gen)
+        .map(|(x, y)| (x % 10) as isize - (y % 10) as isize);
+    Box::new(delta)
+}
+
+fn max_achieve(value: usize) -> HashMap<(isize, isize, isize, isize), usize> {
+    let gen = NumberGenerator::new(value).skip(1);
+    let diff = get_diff_generator(value);
+    let mut window = VecDeque::new();
+    let mut result = HashMap::new();
+    for (value, diff) in gen.zip(diff).take(1999) {
+        window.push_back(diff);
+        while window.len() > 4 {
+            window.pop_front();
+        }
+        if window.len() == 4 {
+            let data = window.iter().collect::<Vec<_>>();
+            let data = (*data[0], *data[1], *data[2], *data[3]);
+            let entry = result.entry(data).or_insert(0);
+            if *entry == 0 {
+                *entry = value % 10;
+            }
+        }
+    }
+    result
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 22;
+
+    /// Initial secret number of every buyer.
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_lines(reader, |x| Ok(parse_number(x, 1)?))
+    }
+
+    fn part1(secrets: &Self::Input) -> Result<usize> {
+        let answer = secrets
+            .iter()
+            .map(|value| get_nth_value(*value, 2000))
+            .sum();
+        Ok(answer)
+    }
+
+    fn part2(secrets: &Self::Input) -> Result<usize> {
+        secrets
+            .iter()
+            .map(|value| max_achieve(*value))
+            .fold(HashMap::new(), |mut acc, x| {
+                for (key, value) in x {
+                    let entry = acc.entry(key).or_insert(0);
+                    *entry += value;
+                }
+                acc
+            })
+            .values().copied()
+            .max().context("Can not find max value")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator_number() {
+        let mut gen = NumberGenerator::new(123);
+        for value in [
+            123,
+            15887950,
+            16495136,
+            527345,
+            704524,
+            1553684,
+            12683156,
+            11100544,
+            12249484,
+            7753432,
+            5908254,
+        ] {
+            assert_eq!(Some(value), gen.next());
+        }
+    }
+
+    #[test]
+    fn test_get_2000() {
+        for (value, expected) in [
+            (1, 8685429),
+            (10, 4700978),
+            (100, 15273692),
+            (2024, 8667524),
+        ] {
+            assert_eq!(get_nth_value(value, 2000), expected);
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut gen = get_diff_generator(123);
+        for value in [-3, 6, -1, -1, 0, 2, -2, 0, -2] {
+            assert_eq!(gen.next(), Some(value));
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::dot::Dot;
+use crate::{Graph, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 23;
+
+    /// Undirected LAN party network.
+    type Input = Graph;
+    type Part1 = usize;
+    type Part2 = Vec<String>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Graph::parse_edges(reader, '-', false)
+    }
+
+    fn part1(graph: &Self::Input) -> Result<usize> {
+        let answer = graph
+            .triangles()
+            .iter()
+            .filter(|x| x.iter().any(|id| graph.node(*id).starts_with('t')))
+            .count();
+        Ok(answer)
+    }
+
+    fn part2(graph: &Self::Input) -> Result<Vec<String>> {
+        let clique = graph.maximum_clique();
+        if clique.is_empty() {
+            return Err(anyhow!("could not find network"));
+        }
+        Ok(clique.into_iter().map(|x| graph.node(x).clone()).collect())
+    }
+
+    /// The whole network with the LAN party of part 2 in red.
+    fn dot(graph: &Self::Input) -> Result<Dot> {
+        let clique = graph.maximum_clique();
+        let mut dot = Dot::graph(graph, false);
+        dot.highlight(clique.into_iter().map(|x| graph.node(x)), "red");
+        Ok(dot)
+    }
+}
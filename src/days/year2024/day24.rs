@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::dot::Dot;
+use crate::netlist::{Gate, Netlist, Operation};
+use crate::parse::ParseError;
+use crate::sections::Sections;
+
+fn parse_values(raw: &str) -> Result<(String, bool)> {
+    let Some((name, value)) = raw.split_once(": ") else {
+        return Err(ParseError::expected(1, "a wire like 'x00: 1'", raw).into());
+    };
+    let value = match value {
+        "1" => true,
+        "0" => false,
+        value => {
+            return Err(ParseError::expected(name.len() + 3, "0 or 1", value).into());
+        }
+    };
+    Ok((name.to_string(), value))
+}
+
+fn get_input_data<R: BufRead>(reader: R) -> Result<(HashMap<String, bool>, Netlist)> {
+    let mut sections = Sections::read(reader)?;
+    let variables = sections.expect("wires")?.parse_lines(parse_values)?.into_iter().collect();
+    let gates = sections.expect("gates")?.parse_lines(|x| x.parse())?;
+    Ok((variables, Netlist::new(gates)?))
+}
+
+/// Gates of the device with lookups by operation and inputs and by output, and the outputs
+/// swapped so far.
+struct Circuit {
+    gates: Vec<Gate>,
+    by_inputs: HashMap<(Operation, [String; 2]), usize>,
+    by_output: HashMap<String, usize>,
+    swapped: Vec<String>,
+}
+
+/// Result of checking one full adder of the circuit.
+enum Check {
+    /// The bit is wired right, with the wire of its carry out.
+    Carry(String),
+    /// The outputs of the gates driving these wires have to be swapped.
+    Swap(String, String),
+}
+
+impl Circuit {
+    fn new(gates: &[Gate]) -> Self {
+        let mut circuit = Self {
+            gates: gates.to_vec(),
+            by_inputs: HashMap::new(),
+            by_output: HashMap::new(),
+            swapped: vec![],
+        };
+        circuit.index();
+        circuit
+    }
+
+    fn index(&mut self) {
+        self.by_inputs = self.gates.iter().enumerate().map(|(i, x)| ((x.operation, x.inputs.clone()), i)).collect();
+        self.by_output = self.gates.iter().enumerate().map(|(i, x)| (x.output.clone(), i)).collect();
+    }
+
+    /// Output of the gate `a operation b`.
+    fn find(&self, operation: Operation, a: &str, b: &str) -> Option<String> {
+        let gate = Gate::new(a, operation, b, "");
+        self.by_inputs.get(&(operation, gate.inputs)).map(|x| self.gates[*x].output.clone())
+    }
+
+    fn driver(&self, wire: &str) -> Option<&Gate> {
+        self.by_output.get(wire).map(|x| &self.gates[*x])
+    }
+
+    /// First gate with `operation` that reads `wire`.
+    fn reader(&self, operation: Operation, wire: &str) -> Option<&Gate> {
+        self.gates.iter().find(|x| x.operation == operation && x.has_input(wire))
+    }
+
+    fn swap(&mut self, a: &str, b: &str) -> Result<()> {
+        let first = *self.by_output.get(a).context(format!("No gate drives {a}"))?;
+        let second = *self.by_output.get(b).context(format!("No gate drives {b}"))?;
+        self.gates[first].output = b.to_string();
+        self.gates[second].output = a.to_string();
+        self.swapped.extend([a.to_string(), b.to_string()]);
+        self.index();
+        Ok(())
+    }
+
+    /// Checks bit `bit` against a full adder: `s = x ^ y`, `z = s ^ carry` and the carry out
+    /// `(x & y) | (s & carry)`. Bit 0 is a half adder without a carry in.
+    fn check_bit(&self, bit: usize, carry: Option<&str>) -> Result<Check> {
+        let (x, y, z) = (format!("x{bit:02}"), format!("y{bit:02}"), format!("z{bit:02}"));
+        let s = self.find(Operation::Xor, &x, &y).context(format!("Bit {bit}: no gate {x} XOR {y}"))?;
+        let a = self.find(Operation::And, &x, &y).context(format!("Bit {bit}: no gate {x} AND {y}"))?;
+        let Some(c) = carry else {
+            return Ok(if s != z { Check::Swap(s, z) } else { Check::Carry(a) });
+        };
+
+        let Some(sum) = self.find(Operation::Xor, &s, c) else {
+            // One of the inputs of the XOR driving z is not what it should be.
+            let gate = self
+                .driver(&z)
+                .filter(|x| x.operation == Operation::Xor && (x.has_input(&s) || x.has_input(c)))
+                .context(format!("Bit {bit}: {z} is not driven by a XOR gate reading {s} or {c}"))?;
+            return Ok(match gate.has_input(c) {
+                true => Check::Swap(s, other_input(gate, c)),
+                false => Check::Swap(c.to_string(), other_input(gate, &s)),
+            });
+        };
+        if sum != z {
+            return Ok(Check::Swap(sum, z));
+        }
+
+        let t = self.find(Operation::And, &s, c).context(format!("Bit {bit}: no gate {s} AND {c}"))?;
+        let Some(next) = self.find(Operation::Or, &a, &t) else {
+            let gate = self
+                .reader(Operation::Or, &t)
+                .or_else(|| self.reader(Operation::Or, &a))
+                .context(format!("Bit {bit}: no OR gate reads {a} or {t}"))?;
+            return Ok(match gate.has_input(&t) {
+                true => Check::Swap(a, other_input(gate, &t)),
+                false => Check::Swap(t, other_input(gate, &a)),
+            });
+        };
+        Ok(Check::Carry(next))
+    }
+}
+
+fn other_input(gate: &Gate, wire: &str) -> String {
+    gate.inputs.iter().find(|x| *x != wire).unwrap_or(&gate.inputs[0]).clone()
+}
+
+/// Swaps a full adder can need before it is wired right. More means the repair goes in circles.
+const MAX_SWAPS_PER_BIT: usize = 4;
+
+/// Repairs the ripple-carry adder of `netlist` bit by bit from the lowest one and returns the
+/// repaired gates and the swapped wires.
+fn repair_adder(netlist: &Netlist) -> Result<(Vec<Gate>, Vec<String>)> {
+    let bits = netlist.bits('x').len();
+    let mut circuit = Circuit::new(netlist.gates());
+    let mut carry: Option<String> = None;
+    for bit in 0..bits {
+        let mut swaps = 0;
+        loop {
+            match circuit.check_bit(bit, carry.as_deref())? {
+                Check::Carry(next) => {
+                    carry = Some(next);
+                    break;
+                }
+                Check::Swap(_, _) if swaps == MAX_SWAPS_PER_BIT => {
+                    return Err(anyhow!("Bit {bit}: still broken after {swaps} swaps"));
+                }
+                Check::Swap(a, b) => {
+                    circuit.swap(&a, &b)?;
+                    swaps += 1;
+                }
+            }
+        }
+    }
+    // The carry out of the highest bit is the highest bit of the sum.
+    let last = format!("z{bits:02}");
+    if let Some(carry) = carry.filter(|x| *x != last) {
+        circuit.swap(&carry, &last)?;
+    }
+    Ok((circuit.gates, circuit.swapped))
+}
+
+/// Number of random additions the repaired adder is checked with.
+const CHECKS: usize = 100;
+
+/// Checks that `netlist` adds `bits`-bit numbers, with random numbers and the extremes.
+fn verify_adder(netlist: &Netlist, bits: usize) -> Result<()> {
+    if bits == 0 || bits >= 64 {
+        return Err(anyhow!("Can not check an adder of {bits} bits"));
+    }
+    let max = (1u64 << bits) - 1;
+    // SplitMix64, a fixed seed keeps the check reproducible.
+    let mut seed = 0x2024_0024u64;
+    let mut random = move || {
+        seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        (z ^ (z >> 31)) & max
+    };
+    let numbers = [(0, 0), (max, 0), (0, max), (max, max), (max, 1)].into_iter();
+    for (x, y) in numbers.chain((0..CHECKS).map(|_| (random(), random()))) {
+        let z = netlist.add(x, y)?;
+        if z != x + y {
+            return Err(anyhow!("The repaired adder computes {x} + {y} = {z}"));
+        }
+    }
+    Ok(())
+}
+
+/// Initial wire values and the gates of the device.
+pub struct Device {
+    variables: HashMap<String, bool>,
+    netlist: Netlist,
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 24;
+
+    type Input = Device;
+    type Part1 = u64;
+    type Part2 = String;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let (variables, netlist) = get_input_data(reader)?;
+        Ok(Device { variables, netlist })
+    }
+
+    fn part1(device: &Self::Input) -> Result<u64> {
+        let values = device.netlist.evaluate(|x| device.variables.get(x).copied())?;
+        Ok(device.netlist.number(&values, 'z'))
+    }
+
+    /// Wires of the gates whose outputs are swapped, sorted and joined with commas.
+    fn part2(device: &Self::Input) -> Result<String> {
+        let (gates, mut swapped) = repair_adder(&device.netlist)?;
+        verify_adder(&Netlist::new(gates)?, device.netlist.bits('x').len())?;
+        swapped.sort();
+        Ok(swapped.join(","))
+    }
+
+    /// The circuit with its input and output bits grouped. If it can be repaired as an adder,
+    /// the swapped wires are red.
+    fn dot(device: &Self::Input) -> Result<Dot> {
+        let mut dot = Dot::netlist(&device.netlist);
+        let netlist = &device.netlist;
+        dot.cluster(netlist.bits('x'), "x").cluster(netlist.bits('y'), "y").cluster(netlist.bits('z'), "z");
+        if let Result::Ok((_, swapped)) = repair_adder(&device.netlist) {
+            dot.highlight(swapped, "red");
+        }
+        Ok(dot)
+    }
+}
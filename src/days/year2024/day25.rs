@@ -0,0 +1,75 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::parse::ParseError;
+use crate::sections::{Section, Sections};
+
+pub type Key = Vec<Vec<bool>>;
+pub type Lock = Vec<Vec<bool>>;
+
+fn has_overlap(lock: &Lock, key: &Key) -> bool{
+    lock
+        .iter()
+        .zip(key.iter())
+        .any(|(x, y)| {
+            x
+                .iter()
+                .zip(y.iter())
+                .any(|(x, y)| x & y )
+
+        })
+}
+
+/// Pins of a lock or key, rows have to be of the same length.
+fn read_window(section: &Section) -> Result<Vec<Vec<bool>>> {
+    let grid = section.parse_grid(|ch| match ch {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(ParseError::expected(0, "'#' or '.'", ch).into()),
+    })?;
+    Ok(grid.rows().map(|x| x.to_vec()).collect())
+}
+
+fn is_lock(data: &[Vec<bool>]) -> bool {
+    data[0].iter().all(|x| *x)
+}
+
+fn read_data<R: BufRead>(reader: R)  -> Result<(Vec<Lock>, Vec<Key>)> {
+    let mut keys = vec![];
+    let mut locks = vec![];
+    for section in Sections::read(reader)? {
+        let data = read_window(&section)?;
+        if is_lock(&data) {
+            locks.push(data);
+        } else {
+            keys.push(data);
+        }
+    }
+
+    Ok((locks, keys))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+    const HAS_PART2: bool = false;
+
+    /// Locks and keys as pin grids.
+    type Input = (Vec<Lock>, Vec<Key>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        read_data(reader)
+    }
+
+    fn part1((locks, keys): &Self::Input) -> Result<usize> {
+        let answer = locks.iter()
+            .flat_map(|x| keys.iter().map(move |y| (x, y)))
+            .filter(|(x, y)| !has_overlap(x, y))
+            .count();
+        Ok(answer)
+    }
+}
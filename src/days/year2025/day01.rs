@@ -0,0 +1,95 @@
+use crate::parse::{parse_lines, parse_number, ParseError};
+use crate::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Rotation {
+    Left(i32),
+    Right(i32),
+}
+
+impl Rotation {
+    fn try_from(value: &str) -> Result<Rotation> {
+        let mut chars = value.chars();
+        let direction = chars.next();
+        let distance = || parse_number::<i32>(chars.as_str(), 2);
+
+        match direction {
+            Some('L') => Ok(Rotation::Left(distance()?)),
+            Some('R') => Ok(Rotation::Right(distance()?)),
+            _ => {
+                let found = direction.map(String::from).unwrap_or_default();
+                Err(ParseError::expected(1, "L or R", found).into())
+            }
+        }
+    }
+}
+
+const MOD: i32 = 100;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Rotation>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_lines(reader, Rotation::try_from)
+    }
+
+    fn part1(rotations: &Self::Input) -> Result<usize> {
+        let mut curr = 50i32;
+        let mut count = 0usize;
+
+        for rotation in rotations {
+            curr = match rotation {
+                Rotation::Left(x) => (curr - x + MOD) % MOD,
+                Rotation::Right(x) => (curr + x + MOD) % MOD,
+            };
+
+            if curr == 0 {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    fn part2(rotations: &Self::Input) -> Result<usize> {
+        let mut curr = 50i32;
+        let mut count = 0usize;
+        let mut prev_zero = 0;
+
+        for rotation in rotations {
+            curr = match rotation {
+                Rotation::Left(x) => curr - x,
+                Rotation::Right(x) => curr + x,
+            };
+
+            if curr < 0 {
+                let inc_value = curr / -MOD + 1 - prev_zero;
+                count += inc_value as usize;
+                curr = curr.rem_euclid(MOD);
+            } else if curr == 0 {
+                count += 1;
+            } else if curr >= 100 {
+                let inc_value = (curr / MOD).max(0);
+                count += inc_value as usize;
+                curr = curr.rem_euclid(MOD);
+            }
+
+            if curr == 0 {
+                prev_zero = 1;
+            } else {
+                prev_zero = 0;
+            }
+        }
+
+        Ok(count)
+    }
+}
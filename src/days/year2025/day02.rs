@@ -0,0 +1,84 @@
+use crate::parse::{parse_lines, parse_pair};
+use crate::Solution;
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+fn found_silly_in_range(a: i64, b: i64) -> Vec<i64> {
+    let min_base = a.ilog10();
+    let half_base = min_base / 2;
+    let mut half_value = 10i64.pow(half_base);
+    let mut result = vec![];
+    loop {
+        let value = half_value * (10i64.pow(half_value.ilog10() + 1)) + half_value;
+        if a <= value && value <= b {
+            result.push(value);
+        }
+        if value > b {
+            break;
+        }
+        half_value += 1
+    }
+
+    result
+}
+
+fn found_silly_in_range_2(a: i64, b: i64) -> Vec<i64> {
+    let max_base = b.ilog10() + 1;
+    let half_base = max_base / 2;
+    let mut result = HashSet::new();
+    for i in 1..10i64.pow(half_base) {
+        let base = i.ilog10() + 1;
+        let mut value = i * 10i64.pow(base) + i; // repeat at least 2 times
+        while value <= b {
+            if a <= value && value <= b {
+                result.insert(value);
+            }
+            value = value * 10i64.pow(base) + i;
+        }
+    }
+    result.into_iter().collect::<Vec<_>>()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Input = Vec<(i64, i64)>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let ranges = parse_lines(reader, |line| {
+            let mut column = 1;
+            let mut ranges = vec![];
+            for range in line.split(',') {
+                if !range.is_empty() {
+                    ranges.push(parse_pair(range, "-", column)?);
+                }
+                column += range.len() + 1;
+            }
+            Ok(ranges)
+        })?
+        .concat();
+        Ok(ranges)
+    }
+
+    fn part1(ranges: &Self::Input) -> Result<i64> {
+        let answer = ranges
+            .iter()
+            .flat_map(|x| found_silly_in_range(x.0, x.1))
+            .sum();
+        Ok(answer)
+    }
+
+    fn part2(ranges: &Self::Input) -> Result<i64> {
+        let answer = ranges
+            .iter()
+            .flat_map(|x| found_silly_in_range_2(x.0, x.1))
+            .sum();
+        Ok(answer)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::parse::{parse_digit, parse_lines};
+use crate::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+fn get_joltage(digits: &[usize], size: usize) -> usize {
+    let mut result = vec![];
+
+    for i in 0..digits.len() {
+        while let Some(&val) = result.last() {
+            if val < digits[i] && digits.len() - i >= size - (result.len() - 1) {
+                result.pop();
+            } else {
+                break;
+            }
+        }
+        if result.len() < size {
+            result.push(digits[i]);
+        }
+    }
+    result.into_iter().fold(0, |acc, item| acc * 10 + item)
+}
+
+fn read_bank(line: &str) -> Result<Vec<usize>> {
+    let digits = line
+        .chars()
+        .enumerate()
+        .map(|(i, ch)| parse_digit(ch, i + 1).map(usize::from))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(digits)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<usize>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_lines(reader, read_bank)
+    }
+
+    fn part1(banks: &Self::Input) -> Result<usize> {
+        let answer = banks.iter().map(|x| get_joltage(x, 2)).sum();
+        Ok(answer)
+    }
+
+    fn part2(banks: &Self::Input) -> Result<usize> {
+        let answer = banks.iter().map(|x| get_joltage(x, 12)).sum();
+        Ok(answer)
+    }
+}
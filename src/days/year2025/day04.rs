@@ -0,0 +1,80 @@
+use crate::maze::{Bounds, Point};
+use crate::render::{Overlay, Palette, Renderer, GREEN, RED, WHITE};
+use crate::{Grid, Solution};
+use anyhow::*;
+use std::io::BufRead;
+use std::path::PathBuf;
+
+fn is_valid(rolls: &Grid<char>, point: Point) -> bool {
+    Bounds::of(rolls)
+        .all_neighbors(point)
+        .filter(|x| rolls[*x] == '@')
+        .count()
+        < 4
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse_chars(reader)
+    }
+
+    fn part1(rolls: &Self::Input) -> Result<usize> {
+        let answer = rolls
+            .find_all(&'@')
+            .filter(|x| is_valid(rolls, *x))
+            .count();
+        Ok(answer)
+    }
+
+    fn part2(rolls: &Self::Input) -> Result<usize> {
+        let mut rolls = rolls.clone();
+        let mut prev_answer = 1;
+        let mut answer = 0;
+        while prev_answer != answer {
+            let current = rolls
+                .find_all(&'@')
+                .filter(|x| is_valid(&rolls, *x))
+                .collect::<Vec<_>>();
+            prev_answer = answer;
+            answer += current.len();
+            for point in current {
+                rolls[point] = '.';
+            }
+        }
+        Ok(answer)
+    }
+
+    /// The rolls being removed round by round, the ones removed next in red.
+    fn render(rolls: &Self::Input, renderer: &Renderer) -> Result<Vec<PathBuf>> {
+        let mut rolls = rolls.clone();
+        let palette = Palette::new(WHITE).with('@', GREEN);
+        let mut animation = renderer.animation();
+        loop {
+            let current = rolls
+                .find_all(&'@')
+                .filter(|x| is_valid(&rolls, *x))
+                .collect::<Vec<_>>();
+            animation.record(|| {
+                let overlay = Overlay::new().with(current.iter().copied(), RED);
+                renderer.draw(&rolls, &palette, Some(&overlay))
+            });
+            if current.is_empty() {
+                break;
+            }
+            for point in current {
+                rolls[point] = '.';
+            }
+        }
+        animation.finish(|| renderer.draw(&rolls, &palette, None));
+        Ok(vec![renderer.save_animation(&animation, "removal")?])
+    }
+}
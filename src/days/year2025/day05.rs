@@ -0,0 +1,58 @@
+use crate::interval::IntervalSet;
+use crate::parse::{parse_number, parse_pair};
+use crate::sections::Sections;
+use crate::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+fn get_range(s: &str) -> Result<(usize, usize)> {
+    Ok(parse_pair(s, "-", 1)?)
+}
+
+/// Merged fresh ingredient ranges and the available ingredient ids.
+pub struct Inventory {
+    intervals: IntervalSet<usize>,
+    ingredients: Vec<usize>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input = Inventory;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut sections = Sections::read(reader)?;
+        let intervals = sections
+            .expect("fresh ranges")?
+            .parse_lines(get_range)?
+            .into_iter()
+            .map(|(begin, end)| begin..=end)
+            .collect();
+        let ingredients = sections
+            .expect("ingredients")?
+            .parse_lines(|x| Ok(parse_number(x, 1)?))?;
+
+        Ok(Inventory {
+            intervals,
+            ingredients,
+        })
+    }
+
+    fn part1(inventory: &Self::Input) -> Result<usize> {
+        let answer = inventory
+            .ingredients
+            .iter()
+            .filter(|&&value| inventory.intervals.contains(value))
+            .count();
+        Ok(answer)
+    }
+
+    fn part2(inventory: &Self::Input) -> Result<usize> {
+        Ok(usize::try_from(inventory.intervals.covered_len())?)
+    }
+}
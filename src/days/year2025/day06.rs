@@ -0,0 +1,143 @@
+use crate::parse::{parse_lines, ParseError};
+use crate::Solution;
+use anyhow::*;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::io::BufRead;
+use std::str::FromStr;
+
+enum Cell {
+    Number(usize),
+    Add,
+    Multiply,
+}
+
+impl FromStr for Cell {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if let Result::Ok(value) = s.parse::<usize>() {
+            Ok(Cell::Number(value))
+        } else {
+            match s {
+                "*" => Ok(Cell::Multiply),
+                "+" => Ok(Cell::Add),
+                _ => Err(Error::msg(format!("Unrecognized cell \"{}\"", s))),
+            }
+        }
+    }
+}
+
+/// A worksheet line, kept as it is after checking that it only has digits, operators and spaces.
+fn read_line(line: &str) -> Result<String> {
+    let wrong = line
+        .char_indices()
+        .find(|(_, x)| !x.is_ascii_digit() && !matches!(x, ' ' | '+' | '*'));
+    if let Some((i, ch)) = wrong {
+        return Err(ParseError::expected(i + 1, "a digit, '+', '*' or a space", ch).into());
+    }
+    Ok(line.to_string())
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    /// Raw lines: part 2 reads the worksheet column by column, so whitespace matters.
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_lines(reader, read_line)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<usize> {
+        let mut expressions: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut answer = 0;
+        for line in lines {
+            let mut idx = 0;
+            for item in line.split(' ') {
+                if item.trim() == "" {
+                    continue;
+                }
+                match item.parse::<Cell>()? {
+                    Cell::Number(value) => {
+                        let entry = expressions.entry(idx).or_insert(vec![]);
+                        (*entry).push(value);
+                    }
+                    Cell::Add => {
+                        answer += expressions
+                            .get(&idx)
+                            .unwrap_or(&vec![])
+                            .iter()
+                            .sum::<usize>()
+                    }
+                    Cell::Multiply => {
+                        answer += expressions
+                            .get(&idx)
+                            .unwrap_or(&vec![])
+                            .iter()
+                            .product::<usize>()
+                    }
+                }
+                idx += 1;
+            }
+        }
+
+        Ok(answer)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<usize> {
+        let matrix = lines
+            .iter()
+            .map(|x| x.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let n = matrix.iter().map(|x| x.len()).max().unwrap_or(0);
+
+        let mut input = String::new();
+        for i in 0..n {
+            for row in &matrix {
+                // Shorter lines are blank at the end.
+                write!(&mut input, "{}", row.get(i).copied().unwrap_or(' '))?;
+            }
+            write!(&mut input, "\r\n")?;
+        }
+        write!(&mut input, "\r\n")?;
+        let mut answer = 0;
+        let mut expression = None;
+        let mut values = vec![];
+        for line in input.lines() {
+            if line.trim() == "" {
+                match expression {
+                    Some(Cell::Add) => {
+                        answer += values.iter().sum::<usize>();
+                    }
+                    Some(Cell::Multiply) => {
+                        answer += values.iter().product::<usize>();
+                    }
+                    _ => return Err(Error::msg("Unexpected expression")),
+                }
+                values.clear();
+                expression = None;
+                continue;
+            }
+            if line.contains("+") {
+                expression = Some(Cell::Add);
+            }
+            if line.contains("*") {
+                expression = Some(Cell::Multiply);
+            }
+            values.push(
+                line.trim_matches(|x| x == '+' || x == '*')
+                    .trim()
+                    .parse::<usize>()?,
+            );
+        }
+
+        Ok(answer)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::parse::{at_line, parse_lines, ParseError};
+use crate::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+/// Rows of the manifold, all as wide as the first. Splitters on the edges would split the beam
+/// out of the manifold, so they are rejected.
+fn read_manifold<R: BufRead>(reader: R) -> Result<Vec<String>> {
+    let lines = parse_lines(reader, |line| {
+        if let Some((i, ch)) = line
+            .char_indices()
+            .find(|(_, x)| !matches!(x, 'S' | '^' | '.'))
+        {
+            return Err(ParseError::expected(i + 1, "'S', '^' or '.'", ch).into());
+        }
+        if line.starts_with('^') || line.ends_with('^') {
+            let column = if line.starts_with('^') { 1 } else { line.len() };
+            return Err(
+                ParseError::new(column, "^", "splitter on the edge of the manifold").into(),
+            );
+        }
+        Ok(line.to_string())
+    })?;
+    let width = lines.first().map_or(0, |x| x.len());
+    if let Some(i) = lines.iter().position(|x| x.len() != width) {
+        let message = format!("expected {width} cells, found {}", lines[i].len());
+        return Err(at_line(
+            ParseError::new(1, &lines[i], message).into(),
+            i + 1,
+            &lines[i],
+        ));
+    }
+    Ok(lines)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        read_manifold(reader)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        let mut prev_line = vec![];
+        for line in lines {
+            if prev_line.is_empty() {
+                prev_line = line.chars().collect::<Vec<_>>();
+                continue;
+            }
+            let mut line = line.chars().collect::<Vec<_>>();
+            for i in 0..line.len() {
+                if prev_line[i] == 'S' {
+                    line[i] = '|';
+                    continue;
+                }
+                if line[i] == '^' && prev_line[i] == '|' {
+                    answer += 1;
+                    line[i - 1] = '|';
+                    line[i + 1] = '|';
+                }
+                if line[i] == '.' && prev_line[i] == '|' {
+                    line[i] = '|';
+                }
+            }
+            prev_line = line;
+        }
+        Ok(answer)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<usize> {
+        let mut prev_line = vec![];
+        for line in lines {
+            if prev_line.is_empty() {
+                prev_line = line
+                    .chars()
+                    .map(|x| if x == 'S' { 1usize } else { 0usize })
+                    .collect::<Vec<_>>();
+                continue;
+            }
+            let mut current_line = vec![0; line.len()];
+            for (i, ch) in line.chars().enumerate() {
+                if ch == '.' {
+                    current_line[i] += prev_line[i];
+                } else if ch == '^' {
+                    current_line[i - 1] += prev_line[i];
+                    current_line[i] = 0;
+                    current_line[i + 1] += prev_line[i];
+                }
+            }
+            prev_line = current_line;
+        }
+        Ok(prev_line.into_iter().sum())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::parse::{parse_lines, parse_numbers, ParseError};
+use crate::union_find::{kruskal, UnionFind};
+use crate::{Params, Solution};
+use anyhow::*;
+use std::collections::BTreeSet;
+use std::io::BufRead;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Point3D {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+impl FromStr for Point3D {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match parse_numbers::<usize>(s, ",", 1)?[..] {
+            [x, y, z] => Ok(Point3D { x, y, z }),
+            _ => Err(ParseError::expected(1, "x,y,z", s).into()),
+        }
+    }
+}
+
+fn get_square_distance(a: Point3D, b: Point3D) -> usize {
+    (a.x.abs_diff(b.x)).pow(2) + (a.y.abs_diff(b.y)).pow(2) + (a.z.abs_diff(b.z)).pow(2)
+}
+
+/// Junction boxes and the number of closest pairs part 1 connects, 1000 in the real puzzle and
+/// 10 in the example.
+pub struct Playground {
+    points: Vec<Point3D>,
+    pairs: usize,
+}
+
+fn sorted_distances(points: &[Point3D]) -> BTreeSet<(usize, usize, usize)> {
+    let mut distances = BTreeSet::new();
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            distances.insert((get_square_distance(points[i], points[j]), i, j));
+        }
+    }
+    distances
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Input = Playground;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Self::parse_with(reader, &Params::new())
+    }
+
+    fn parse_with<R: BufRead>(reader: R, params: &Params) -> Result<Self::Input> {
+        params.expect_only(&["pairs"])?;
+        Ok(Playground { points: parse_lines(reader, str::parse)?, pairs: params.get("pairs", 1000)? })
+    }
+
+    fn part1(playground: &Self::Input) -> Result<usize> {
+        let Playground { ref points, pairs: cabels } = *playground;
+        let distances = sorted_distances(points);
+        if distances.len() < cabels {
+            return Err(anyhow!("Not enough pairs to connect"));
+        }
+        let mut circuits = UnionFind::new(points.len());
+        for (_, i, j) in distances.into_iter().take(cabels) {
+            circuits.union(i, j);
+        }
+        let mut joints = circuits.sizes();
+        joints.sort();
+        joints.reverse();
+        let answer = joints.iter().take(3).product();
+
+        Ok(answer)
+    }
+
+    fn part2(playground: &Self::Input) -> Result<usize> {
+        let points = &playground.points;
+        let cables = kruskal(points.len(), sorted_distances(points)).collect::<Vec<_>>();
+        if cables.len() + 1 < points.len() {
+            return Err(anyhow!("The boxes do not form a single circuit"));
+        }
+        let (_, i, j) = cables.last().context("Nothing to connect")?;
+        Ok(points[*i].x * points[*j].x)
+    }
+}
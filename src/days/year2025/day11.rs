@@ -0,0 +1,93 @@
+use crate::dot::Dot;
+use crate::{Graph, Solution};
+use anyhow::*;
+use num_bigint::BigUint;
+use std::io::BufRead;
+
+const YOU_NODE: &str = "you";
+const OUT_NODE: &str = "out";
+const SVR_NODE: &str = "svr";
+const FFT_NODE: &str = "fft";
+const DAC_NODE: &str = "dac";
+
+fn variants(graph: &Graph, in_node: &str, out_node: &str) -> Result<BigUint> {
+    let in_id = graph.id(in_node).context(format!("No node {in_node}"))?;
+    let out_id = graph.id(out_node).context(format!("No node {out_node}"))?;
+    graph.count_paths(in_id, out_id)
+}
+
+/// Paths from `in_node` to `out_node` that pass through `stops` in the given order.
+fn variants_through(
+    graph: &Graph,
+    in_node: &str,
+    stops: &[&str],
+    out_node: &str,
+) -> Result<BigUint> {
+    let nodes = [&[in_node], stops, &[out_node]].concat();
+    let mut answer = BigUint::from(1u8);
+    for pair in nodes.windows(2) {
+        answer *= variants(graph, pair[0], pair[1])?;
+    }
+    Ok(answer)
+}
+
+/// Nodes on a path from `in_node` to `out_node`.
+fn path_nodes<'a>(graph: &'a Graph, in_node: &str, out_node: &str) -> Result<Vec<&'a String>> {
+    let in_id = graph.id(in_node).context(format!("No node {in_node}"))?;
+    let out_id = graph.id(out_node).context(format!("No node {out_node}"))?;
+    let from_in = graph.reachable(in_id);
+    let to_out = graph.reversed().reachable(out_id);
+    Ok(graph
+        .ids()
+        .filter(|x| from_in[*x] && to_out[*x])
+        .map(|x| graph.node(x))
+        .collect())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 11;
+
+    type Input = Graph;
+    type Part1 = BigUint;
+    type Part2 = BigUint;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Graph::parse_adjacency(reader)
+    }
+
+    fn part1(graph: &Self::Input) -> Result<BigUint> {
+        variants(graph, YOU_NODE, OUT_NODE)
+    }
+
+    fn part2(graph: &Self::Input) -> Result<BigUint> {
+        // In a DAG only one of the orders can have paths.
+        let fft_first = variants_through(graph, SVR_NODE, &[FFT_NODE, DAC_NODE], OUT_NODE)?;
+        let dac_first = variants_through(graph, SVR_NODE, &[DAC_NODE, FFT_NODE], OUT_NODE)?;
+        Ok(fft_first + dac_first)
+    }
+
+    /// Devices on the paths of part 1 in blue and on the paths of part 2 in orange. A part is
+    /// skipped if the input does not have its devices.
+    fn dot(graph: &Self::Input) -> Result<Dot> {
+        let mut dot = Dot::graph(graph, true);
+        let has_nodes = |nodes: &[&str]| nodes.iter().all(|x| graph.id(*x).is_some());
+        for stops in [[FFT_NODE, DAC_NODE], [DAC_NODE, FFT_NODE]] {
+            if !has_nodes(&[SVR_NODE, FFT_NODE, DAC_NODE, OUT_NODE])
+                || variants_through(graph, SVR_NODE, &stops, OUT_NODE)? == BigUint::ZERO
+            {
+                continue;
+            }
+            let nodes = [&[SVR_NODE], &stops[..], &[OUT_NODE]].concat();
+            for pair in nodes.windows(2) {
+                dot.highlight(path_nodes(graph, pair[0], pair[1])?, "orange");
+            }
+        }
+        if has_nodes(&[YOU_NODE, OUT_NODE]) {
+            dot.highlight(path_nodes(graph, YOU_NODE, OUT_NODE)?, "blue");
+        }
+        Ok(dot)
+    }
+}
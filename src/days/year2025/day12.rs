@@ -0,0 +1,215 @@
+use crate::parse::ParseError;
+use crate::sections::Sections;
+use crate::Solution;
+use anyhow::*;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Width and height that every present shape fits in.
+const SHAPE_SIZE: usize = 3;
+
+type Row = [bool; SHAPE_SIZE];
+
+#[derive(Eq, PartialEq, Clone, Copy, Hash)]
+pub struct BitMap {
+    size: usize,
+    bitmap: [Row; SHAPE_SIZE],
+}
+
+impl BitMap {
+    fn new(rows: &[Row]) -> Self {
+        let mut bitmap = [[false; SHAPE_SIZE]; SHAPE_SIZE];
+        bitmap[..rows.len()].copy_from_slice(rows);
+        let size = rows.iter().flatten().filter(|x| **x).count();
+        Self { bitmap, size }
+    }
+
+    fn rotate(&self) -> Self {
+        let mut new_bitmap = [[false; SHAPE_SIZE]; SHAPE_SIZE];
+        for (i, row) in self.bitmap.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                new_bitmap[j][SHAPE_SIZE - i - 1] = *cell;
+            }
+        }
+
+        Self {
+            size: self.size,
+            bitmap: new_bitmap,
+        }
+    }
+
+    fn flip_x(&self) -> Self {
+        let mut new_bitmap = [[false; SHAPE_SIZE]; SHAPE_SIZE];
+        for (i, row) in self.bitmap.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                new_bitmap[i][SHAPE_SIZE - j - 1] = *cell;
+            }
+        }
+        Self {
+            size: self.size,
+            bitmap: new_bitmap,
+        }
+    }
+
+    fn flip_y(&self) -> Self {
+        let mut new_bitmap = [[false; SHAPE_SIZE]; SHAPE_SIZE];
+        for (i, row) in self.bitmap.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                new_bitmap[SHAPE_SIZE - i - 1][j] = *cell;
+            }
+        }
+        Self {
+            size: self.size,
+            bitmap: new_bitmap,
+        }
+    }
+}
+
+impl Display for BitMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for line in self.bitmap.iter() {
+            for cell in line {
+                let ch = match cell {
+                    true => '#',
+                    false => '.',
+                };
+                write!(f, "{}", ch)?;
+            }
+            writeln!(f)?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+fn can_fit_region(
+    width: usize,
+    height: usize,
+    gifts_count: Vec<usize>,
+    gifts: &[HashSet<BitMap>],
+) -> bool {
+    gifts
+        .iter()
+        .map(|x| x.iter().next().unwrap())
+        .map(|x| x.size)
+        .zip(gifts_count.iter())
+        .map(|(size, &count)| size * count)
+        .sum::<usize>()
+        <= width * height
+}
+
+/// All rotations of the shape, its mirror image and its upside-down image.
+fn orientations(mut bitmap: BitMap) -> HashSet<BitMap> {
+    let mut bitmaps = HashSet::new();
+    for _ in 0..4 {
+        bitmap = bitmap.rotate();
+        bitmaps.insert(bitmap);
+    }
+
+    bitmap = bitmap.flip_x();
+    for _ in 0..4 {
+        bitmap = bitmap.rotate();
+        bitmaps.insert(bitmap);
+    }
+
+    bitmap = bitmap.flip_x().flip_y();
+    for _ in 0..4 {
+        bitmap = bitmap.rotate();
+        bitmaps.insert(bitmap);
+    }
+    bitmaps
+}
+
+/// `##.` line of a shape, at most [`SHAPE_SIZE`] cells.
+fn parse_shape_row(line: &str) -> Result<Row> {
+    let mut row = [false; SHAPE_SIZE];
+    for (j, ch) in line.chars().enumerate() {
+        if j >= SHAPE_SIZE {
+            return Err(ParseError::new(j + 1, line, format!("shapes have to fit in {SHAPE_SIZE}x{SHAPE_SIZE}")).into());
+        }
+        row[j] = match ch {
+            '#' => true,
+            '.' => false,
+            _ => return Err(ParseError::expected(j + 1, "'#' or '.'", ch).into()),
+        };
+    }
+    Ok(row)
+}
+
+/// `WxH: counts` line of a region.
+fn parse_region(line: &str) -> Result<(usize, usize, Vec<usize>)> {
+    let (size, counts) = line
+        .split_once(':')
+        .context("No ':' after the region size")?;
+    let (width, height) = size.split_once('x').context("No 'x' in the region size")?;
+    let gifts_count = counts
+        .split_whitespace()
+        .map(|x| x.parse::<usize>().map_err(Error::from))
+        .collect::<Result<Vec<usize>>>()?;
+    Ok((width.parse()?, height.parse()?, gifts_count))
+}
+
+/// Every orientation of each present shape, and the regions to pack as (width, height, counts).
+pub struct Presents {
+    gifts: Vec<HashSet<BitMap>>,
+    regions: Vec<(usize, usize, Vec<usize>)>,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+    const HAS_PART2: bool = false;
+
+    type Input = Presents;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut gifts = vec![];
+        let mut regions = vec![];
+        for section in Sections::read(reader)? {
+            if section.label.is_some() {
+                let mut count = 0;
+                let rows = section.parse_lines(|line| {
+                    count += 1;
+                    match count > SHAPE_SIZE {
+                        true => Err(ParseError::new(1, line, format!("shapes have to fit in {SHAPE_SIZE}x{SHAPE_SIZE}")).into()),
+                        false => parse_shape_row(line),
+                    }
+                })?;
+                gifts.push(orientations(BitMap::new(&rows)));
+            } else {
+                regions.extend(section.parse_lines(parse_region)?);
+            }
+        }
+        Ok(Presents { gifts, regions })
+    }
+
+    fn part1(presents: &Self::Input) -> Result<usize> {
+        let answer = Arc::new(Mutex::new(0usize));
+        let gifts = Arc::new(presents.gifts.clone());
+        let mut handles = vec![];
+        for (width, height, gifts_count) in presents.regions.iter().cloned() {
+            let gifts = Arc::clone(&gifts);
+            let answer = Arc::clone(&answer);
+            let handle = thread::spawn(move || {
+                if can_fit_region(width, height, gifts_count, gifts.as_ref()) {
+                    let mut num = answer.lock().unwrap();
+                    *num += 1;
+                }
+            });
+            handles.push(handle);
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let answer = answer.lock().unwrap();
+        Ok(*answer)
+    }
+}
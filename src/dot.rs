@@ -0,0 +1,253 @@
+use crate::netlist::Netlist;
+use crate::Graph;
+use anyhow::*;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::hash::Hash;
+use std::path::Path;
+
+#[derive(Clone, Debug)]
+struct Node {
+    id: String,
+    label: Option<String>,
+    shape: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+struct Edge {
+    from: String,
+    to: String,
+    label: Option<String>,
+}
+
+/// Graph in the Graphviz DOT language, rendered offline with `dot -Tsvg graph.dot -o graph.svg`.
+///
+/// Nodes are identified by their name and added by edges as needed. Highlighted nodes and the edges
+/// between them are drawn in a color, later highlights win. The nodes of a cluster are drawn together
+/// in a box.
+#[derive(Clone, Debug, Default)]
+pub struct Dot {
+    directed: bool,
+    attributes: Vec<(String, String)>,
+    nodes: Vec<Node>,
+    ids: HashMap<String, usize>,
+    edges: Vec<Edge>,
+    highlights: Vec<(HashSet<String>, String)>,
+    clusters: Vec<(HashSet<String>, String)>,
+}
+
+impl Dot {
+    pub fn new(directed: bool) -> Self {
+        Self { directed, ..Self::default() }
+    }
+
+    /// Every node and edge of `graph`. For an undirected graph an edge stored in both directions
+    /// is drawn once.
+    pub fn graph<N: Clone + Eq + Hash + Display>(graph: &Graph<N>, directed: bool) -> Self {
+        let mut dot = Self::new(directed);
+        for id in graph.ids() {
+            dot.node(graph.node(id), None);
+        }
+        for (from, to) in graph.edges() {
+            if directed || from < to || !graph.has_edge(to, from) {
+                dot.edge(graph.node(from), graph.node(to), None);
+            }
+        }
+        dot
+    }
+
+    /// Wires of `netlist` flowing left to right. A wire driven by a gate is a box labeled with
+    /// the operation of the gate, the inputs of the circuit are ellipses.
+    pub fn netlist(netlist: &Netlist) -> Self {
+        let mut dot = Self::new(true);
+        dot.attribute("rankdir", "LR");
+        for wire in netlist.wires() {
+            dot.node(wire, None);
+        }
+        for gate in netlist.gates() {
+            dot.node(&gate.output, Some(&format!("{}\n{}", gate.output, gate.operation)));
+            dot.nodes[dot.ids[&gate.output]].shape = Some("box".to_string());
+            for input in &gate.inputs {
+                dot.edge(input, &gate.output, None);
+            }
+        }
+        dot
+    }
+
+    /// Graph attribute like `rankdir`.
+    pub fn attribute(&mut self, name: &str, value: &str) -> &mut Self {
+        self.attributes.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds the node `id`, or sets the label of an existing one. Without a label the id is shown.
+    pub fn node(&mut self, id: impl Display, label: Option<&str>) -> &mut Self {
+        let id = id.to_string();
+        let index = match self.ids.get(&id) {
+            Some(index) => *index,
+            None => {
+                self.ids.insert(id.clone(), self.nodes.len());
+                self.nodes.push(Node { id, label: None, shape: None });
+                self.nodes.len() - 1
+            }
+        };
+        if let Some(label) = label {
+            self.nodes[index].label = Some(label.to_string());
+        }
+        self
+    }
+
+    pub fn edge(&mut self, from: impl Display, to: impl Display, label: Option<&str>) -> &mut Self {
+        let (from, to) = (from.to_string(), to.to_string());
+        self.node(&from, None).node(&to, None);
+        self.edges.push(Edge { from, to, label: label.map(|x| x.to_string()) });
+        self
+    }
+
+    /// Draws `nodes` and the edges between them in `color`, a Graphviz color name or `#rrggbb`.
+    pub fn highlight<T: Display>(&mut self, nodes: impl IntoIterator<Item = T>, color: &str) -> &mut Self {
+        let nodes = nodes.into_iter().map(|x| x.to_string()).collect();
+        self.highlights.push((nodes, color.to_string()));
+        self
+    }
+
+    /// Groups `nodes` into a box titled `label`. A node goes to the first cluster that has it.
+    pub fn cluster<T: Display>(&mut self, nodes: impl IntoIterator<Item = T>, label: &str) -> &mut Self {
+        let nodes = nodes.into_iter().map(|x| x.to_string()).collect();
+        self.clusters.push((nodes, label.to_string()));
+        self
+    }
+
+    /// Writes the graph to `path`, creating its directory.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|x| !x.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).with_context(|| format!("Can not create {}", parent.display()))?;
+        }
+        fs::write(path, self.to_string()).with_context(|| format!("Can not write {}", path.display()))
+    }
+
+    /// Color of the last highlight that has all of `nodes`.
+    fn color(&self, nodes: &[&str]) -> Option<&str> {
+        let (_, color) = self.highlights.iter().rev().find(|(x, _)| nodes.iter().all(|node| x.contains(*node)))?;
+        Some(color)
+    }
+
+    fn write_node(&self, f: &mut Formatter<'_>, node: &Node, indent: &str) -> std::fmt::Result {
+        let mut attributes = vec![];
+        if let Some(label) = &node.label {
+            attributes.push(format!("label={}", quote(label)));
+        }
+        if let Some(shape) = &node.shape {
+            attributes.push(format!("shape={}", quote(shape)));
+        }
+        if let Some(color) = self.color(&[&node.id]) {
+            attributes.push(format!("color={}, fontcolor={}, penwidth=2", quote(color), quote(color)));
+        }
+        write!(f, "{indent}{}", quote(&node.id))?;
+        if !attributes.is_empty() {
+            write!(f, " [{}]", attributes.join(", "))?;
+        }
+        writeln!(f, ";")
+    }
+}
+
+/// Identifier or string in double quotes, with newlines as `\n`.
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+impl Display for Dot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} {{", if self.directed { "digraph" } else { "graph" })?;
+        for (name, value) in &self.attributes {
+            writeln!(f, "    {name}={};", quote(value))?;
+        }
+        let cluster = |node: &Node| self.clusters.iter().position(|(nodes, _)| nodes.contains(&node.id));
+        for (i, (_, label)) in self.clusters.iter().enumerate() {
+            writeln!(f, "    subgraph \"cluster_{i}\" {{")?;
+            writeln!(f, "        label={};", quote(label))?;
+            for node in self.nodes.iter().filter(|x| cluster(x) == Some(i)) {
+                self.write_node(f, node, "        ")?;
+            }
+            writeln!(f, "    }}")?;
+        }
+        for node in self.nodes.iter().filter(|x| cluster(x).is_none()) {
+            self.write_node(f, node, "    ")?;
+        }
+        let arrow = if self.directed { "->" } else { "--" };
+        for edge in &self.edges {
+            let mut attributes = vec![];
+            if let Some(label) = &edge.label {
+                attributes.push(format!("label={}", quote(label)));
+            }
+            if let Some(color) = self.color(&[&edge.from, &edge.to]) {
+                attributes.push(format!("color={}, penwidth=2", quote(color)));
+            }
+            write!(f, "    {} {arrow} {}", quote(&edge.from), quote(&edge.to))?;
+            if !attributes.is_empty() {
+                write!(f, " [{}]", attributes.join(", "))?;
+            }
+            writeln!(f, ";")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::netlist::Gate;
+
+    #[test]
+    fn test_graph() {
+        let mut graph = Graph::new();
+        graph.add_undirected_edge("a", "b");
+        graph.add_undirected_edge("b", "c");
+        graph.add_edge("c", "d");
+        let mut dot = Dot::graph(&graph, false);
+        dot.node("d", Some("say \"d\"")).highlight(["a", "b"], "red").edge("a", "d", Some("x"));
+        let expected = r#"graph {
+    "a" [color="red", fontcolor="red", penwidth=2];
+    "b" [color="red", fontcolor="red", penwidth=2];
+    "c";
+    "d" [label="say \"d\""];
+    "a" -- "b" [color="red", penwidth=2];
+    "b" -- "c";
+    "c" -- "d";
+    "a" -- "d" [label="x"];
+}
+"#;
+        assert_eq!(expected, dot.to_string());
+    }
+
+    #[test]
+    fn test_netlist_clusters() {
+        let gates = ["x00 XOR y00 -> z00", "x00 AND y00 -> z01", "z00 OR x00 -> xkm"].map(|x| x.parse::<Gate>().unwrap());
+        let netlist = Netlist::new(gates.to_vec()).unwrap();
+        let mut dot = Dot::netlist(&netlist);
+        dot.cluster(netlist.bits('x'), "x").cluster(netlist.bits('z'), "z").highlight(["z01"], "red");
+        let expected = r#"digraph {
+    rankdir="LR";
+    subgraph "cluster_0" {
+        label="x";
+        "x00";
+    }
+    subgraph "cluster_1" {
+        label="z";
+        "z00" [label="z00\nXOR", shape="box"];
+        "z01" [label="z01\nAND", shape="box", color="red", fontcolor="red", penwidth=2];
+    }
+    "y00";
+    "xkm" [label="xkm\nOR", shape="box"];
+    "x00" -> "z00";
+    "y00" -> "z00";
+    "x00" -> "z01";
+    "y00" -> "z01";
+    "x00" -> "xkm";
+    "z00" -> "xkm";
+}
+"#;
+        assert_eq!(expected, dot.to_string());
+    }
+}
@@ -0,0 +1,430 @@
+use crate::parse::{at_line, ParseError};
+use anyhow::*;
+use num_bigint::BigUint;
+use std::borrow::Borrow;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+use std::io::BufRead;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Directed graph with interned nodes. Nodes get ids `0..len()` in the order they are added and
+/// edges are kept as adjacency lists of ids.
+#[derive(Clone, Debug)]
+pub struct Graph<N = String> {
+    nodes: Vec<N>,
+    ids: HashMap<N, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self { nodes: vec![], ids: HashMap::new(), edges: vec![] }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Id of `node`, adding it if it is new.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(id) = self.ids.get(&node) {
+            return *id;
+        }
+        self.nodes.push(node.clone());
+        self.edges.push(vec![]);
+        self.ids.insert(node, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    /// Adds both nodes if needed. Parallel edges are kept.
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push(to);
+    }
+
+    /// Edges in both directions.
+    pub fn add_undirected_edge(&mut self, a: N, b: N) {
+        self.add_edge(a.clone(), b.clone());
+        self.add_edge(b, a);
+    }
+
+    pub fn id<Q: Hash + Eq + ?Sized>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+    {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: usize) -> &N {
+        &self.nodes[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn ids(&self) -> Range<usize> {
+        0..self.nodes.len()
+    }
+
+    pub fn neighbors(&self, id: usize) -> &[usize] {
+        &self.edges[id]
+    }
+
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.edges[from].contains(&to)
+    }
+
+    /// All edges as `(from, to)` ids.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.edges.iter().enumerate().flat_map(|(from, x)| x.iter().map(move |to| (from, *to)))
+    }
+
+    /// Same nodes with the same ids and every edge turned around.
+    pub fn reversed(&self) -> Self {
+        let mut edges = vec![vec![]; self.len()];
+        for (from, to) in self.edges() {
+            edges[to].push(from);
+        }
+        Self { nodes: self.nodes.clone(), ids: self.ids.clone(), edges }
+    }
+
+    /// Graph of the given nodes and the edges between them. Nodes get new ids in the given order.
+    pub fn subgraph(&self, ids: &[usize]) -> Self {
+        let mut graph = Self::new();
+        for id in ids {
+            graph.add_node(self.nodes[*id].clone());
+        }
+        for id in ids {
+            for to in self.edges[*id].iter().filter(|x| ids.contains(x)) {
+                graph.add_edge(self.nodes[*id].clone(), self.nodes[*to].clone());
+            }
+        }
+        graph
+    }
+
+    /// Whether every node can be reached from `from`, indexed by id.
+    pub fn reachable(&self, from: usize) -> Vec<bool> {
+        let mut visited = vec![false; self.len()];
+        let mut stack = vec![from];
+        visited[from] = true;
+        while let Some(id) = stack.pop() {
+            for next in &self.edges[id] {
+                if !visited[*next] {
+                    visited[*next] = true;
+                    stack.push(*next);
+                }
+            }
+        }
+        visited
+    }
+}
+
+impl<N: Clone + Eq + Hash + Display> Graph<N> {
+    /// Ids ordered so that every edge points forward. Fails with the nodes of a cycle if there is
+    /// one.
+    pub fn topological_sort(&self) -> Result<Vec<usize>> {
+        self.topological_order(self.ids(), &vec![true; self.len()])
+    }
+
+    /// Number of distinct paths from `from` to `to`. Fails if a cycle lies on such a path, as
+    /// there would be infinitely many.
+    pub fn count_paths(&self, from: usize, to: usize) -> Result<BigUint> {
+        let leads_to = self.reversed().reachable(to);
+        if !leads_to[from] {
+            return Ok(BigUint::default());
+        }
+        let mut counts = vec![BigUint::default(); self.len()];
+        counts[from] = BigUint::from(1u8);
+        for id in self.topological_order([from], &leads_to)? {
+            let count = counts[id].clone();
+            for next in self.edges[id].iter().filter(|x| leads_to[**x]) {
+                counts[*next] += &count;
+            }
+        }
+        Ok(counts.swap_remove(to))
+    }
+
+    /// Topological order of the `allowed` nodes reachable from `roots` through `allowed` nodes,
+    /// from reversed depth-first post-order.
+    fn topological_order(&self, roots: impl IntoIterator<Item = usize>, allowed: &[bool]) -> Result<Vec<usize>> {
+        const NEW: u8 = 0;
+        const OPEN: u8 = 1;
+        const DONE: u8 = 2;
+        let mut states = vec![NEW; self.len()];
+        let mut order = vec![];
+        for root in roots {
+            if !allowed[root] || states[root] != NEW {
+                continue;
+            }
+            // Nodes on the current path with the index of their next edge to follow.
+            let mut stack = vec![(root, 0)];
+            states[root] = OPEN;
+            while let Some(&(id, edge)) = stack.last() {
+                let Some(&next) = self.edges[id].get(edge) else {
+                    states[id] = DONE;
+                    order.push(id);
+                    stack.pop();
+                    continue;
+                };
+                stack.last_mut().unwrap().1 += 1;
+                if !allowed[next] {
+                    continue;
+                }
+                match states[next] {
+                    NEW => {
+                        states[next] = OPEN;
+                        stack.push((next, 0));
+                    }
+                    OPEN => {
+                        let start = stack.iter().position(|(x, _)| *x == next).unwrap();
+                        let cycle = stack[start..].iter().map(|(x, _)| x).chain([&next]);
+                        let cycle = cycle.map(|x| self.nodes[*x].to_string()).collect::<Vec<_>>();
+                        return Err(anyhow!("Cycle {}", cycle.join(" -> ")));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        order.reverse();
+        Ok(order)
+    }
+}
+
+/// Cliques of undirected graphs, every edge has to be added in both directions.
+impl<N: Clone + Eq + Hash + Ord> Graph<N> {
+    /// Every triangle once, as ids sorted by node.
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        let adjacency = self.adjacency_sets();
+        let mut triangles = vec![];
+        for a in self.ids() {
+            for b in adjacency[a].iter().filter(|x| **x > a) {
+                for c in adjacency[a].intersection(&adjacency[*b]).filter(|x| *x > b) {
+                    let mut triangle = [a, *b, *c];
+                    triangle.sort_by_key(|x| &self.nodes[*x]);
+                    triangles.push(triangle);
+                }
+            }
+        }
+        triangles.sort_by(|a, b| self.cmp_ids(a, b));
+        triangles
+    }
+
+    /// Every maximal clique, found with Bron–Kerbosch with pivoting over a degeneracy ordering.
+    /// Cliques are ids sorted by node and come in the order of their nodes.
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let adjacency = self.adjacency_sets();
+        let order = degeneracy_order(&adjacency);
+        let mut position = vec![0; self.len()];
+        for (i, id) in order.iter().enumerate() {
+            position[*id] = i;
+        }
+
+        let mut cliques = vec![];
+        for id in order {
+            let (later, earlier): (HashSet<_>, HashSet<_>) = adjacency[id].iter().partition(|x| position[**x] > position[id]);
+            bron_kerbosch(&adjacency, &mut vec![id], later, earlier, &mut cliques);
+        }
+        for clique in cliques.iter_mut() {
+            clique.sort_by_key(|x| &self.nodes[*x]);
+        }
+        cliques.sort_by(|a, b| self.cmp_ids(a, b));
+        cliques
+    }
+
+    /// Largest clique as ids sorted by node. Of several largest the one with the smallest nodes.
+    pub fn maximum_clique(&self) -> Vec<usize> {
+        let cliques = self.maximal_cliques();
+        let size = cliques.iter().map(|x| x.len()).max().unwrap_or(0);
+        cliques.into_iter().find(|x| x.len() == size).unwrap_or_default()
+    }
+
+    fn adjacency_sets(&self) -> Vec<HashSet<usize>> {
+        let edges = self.edges.iter().enumerate();
+        edges.map(|(id, x)| x.iter().copied().filter(|x| *x != id).collect()).collect()
+    }
+
+    fn cmp_ids(&self, a: &[usize], b: &[usize]) -> Ordering {
+        a.iter().map(|x| &self.nodes[*x]).cmp(b.iter().map(|x| &self.nodes[*x]))
+    }
+}
+
+/// Nodes in the order of repeatedly removing one with the fewest remaining neighbors. Starting from
+/// such nodes keeps the candidate sets of Bron–Kerbosch small.
+fn degeneracy_order(adjacency: &[HashSet<usize>]) -> Vec<usize> {
+    let mut degrees = adjacency.iter().map(|x| x.len()).collect::<Vec<_>>();
+    let mut remaining = degrees.iter().enumerate().map(|(id, x)| (*x, id)).collect::<BTreeSet<_>>();
+    let mut order = vec![];
+    while let Some((_, id)) = remaining.pop_first() {
+        order.push(id);
+        for next in &adjacency[id] {
+            if remaining.remove(&(degrees[*next], *next)) {
+                degrees[*next] -= 1;
+                remaining.insert((degrees[*next], *next));
+            }
+        }
+    }
+    order
+}
+
+/// Adds to `cliques` every maximal clique that extends `clique` with nodes of `candidates` and
+/// none of `excluded`.
+fn bron_kerbosch(
+    adjacency: &[HashSet<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: HashSet<usize>,
+    mut excluded: HashSet<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    // Neighbors of the pivot are tried through the other nodes, every maximal clique contains the
+    // pivot or one of its non-neighbors.
+    let Some(pivot) = candidates
+        .iter()
+        .chain(&excluded)
+        .max_by_key(|x| (adjacency[**x].intersection(&candidates).count(), Reverse(**x)))
+    else {
+        cliques.push(clique.clone());
+        return;
+    };
+    let mut branches = candidates.difference(&adjacency[*pivot]).copied().collect::<Vec<_>>();
+    branches.sort();
+    for id in branches {
+        let neighbors = &adjacency[id];
+        clique.push(id);
+        bron_kerbosch(
+            adjacency,
+            clique,
+            candidates.intersection(neighbors).copied().collect(),
+            excluded.intersection(neighbors).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(&id);
+        excluded.insert(id);
+    }
+}
+
+impl<N: Clone + Eq + Hash + FromStr> Graph<N>
+where
+    N::Err: std::error::Error + Send + Sync + 'static,
+{
+    /// Reads `from<separator>to` lines up to the first empty line or the end of the input. Every
+    /// edge is added in both directions unless `directed` is set.
+    pub fn parse_edges<R: BufRead>(reader: R, separator: char, directed: bool) -> Result<Self> {
+        let mut graph = Self::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                break;
+            }
+            let edge = line
+                .split_once(separator)
+                .ok_or_else(|| ParseError::expected(1, &format!("an edge like a{separator}b"), line.as_str()))
+                .map_err(Error::from)
+                .and_then(|(from, to)| Ok((from.trim().parse()?, to.trim().parse()?)))
+                .map_err(|x| at_line(x, i + 1, &line))?;
+            if directed {
+                graph.add_edge(edge.0, edge.1);
+            } else {
+                graph.add_undirected_edge(edge.0, edge.1);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Reads `node: a b c` lines with the targets of the directed edges of every node, up to the
+    /// first empty line or the end of the input.
+    pub fn parse_adjacency<R: BufRead>(reader: R) -> Result<Self> {
+        let mut graph = Self::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                break;
+            }
+            let (node, targets) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::expected(1, "a node followed by ':'", line.as_str()))
+                .map_err(|x| at_line(x.into(), i + 1, &line))?;
+            let node = node.trim().parse::<N>().map_err(|x| at_line(x.into(), i + 1, &line))?;
+            graph.add_node(node.clone());
+            for target in targets.split_whitespace() {
+                graph.add_edge(node.clone(), target.parse().map_err(|x: N::Err| at_line(x.into(), i + 1, &line))?);
+            }
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "\
+a: b c
+b: d
+c: d e
+d: e
+";
+
+    #[test]
+    fn test_parse_and_count_paths() {
+        let graph = Graph::<String>::parse_adjacency(TEST.as_bytes()).unwrap();
+        assert_eq!(5, graph.len());
+        let (a, d, e) = (graph.id("a").unwrap(), graph.id("d").unwrap(), graph.id("e").unwrap());
+        assert!(graph.has_edge(d, e) && !graph.has_edge(e, d));
+        assert_eq!(BigUint::from(3u8), graph.count_paths(a, e).unwrap());
+        assert_eq!(BigUint::from(0u8), graph.count_paths(e, a).unwrap());
+        assert_eq!(BigUint::from(3u8), graph.reversed().count_paths(e, a).unwrap());
+        assert_eq!(vec![true, true, true, true, true], graph.reachable(a));
+        assert_eq!(vec![false, false, false, true, true], graph.reachable(d));
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = Graph::<String>::parse_adjacency(TEST.as_bytes()).unwrap();
+        let order = graph.topological_sort().unwrap();
+        for (from, to) in graph.edges() {
+            assert!(order.iter().position(|x| *x == from) < order.iter().position(|x| *x == to));
+        }
+
+        let cyclic = Graph::<u32>::parse_edges("1|2\n2|3\n3|1\n3|4\n".as_bytes(), '|', true).unwrap();
+        assert_eq!("Cycle 1 -> 2 -> 3 -> 1", cyclic.topological_sort().unwrap_err().to_string());
+        let (one, four) = (cyclic.id(&1).unwrap(), cyclic.id(&4).unwrap());
+        assert!(cyclic.count_paths(one, four).is_err());
+        let acyclic = cyclic.subgraph(&[cyclic.id(&3).unwrap(), four, cyclic.id(&2).unwrap()]);
+        let order = acyclic.topological_sort().unwrap();
+        assert_eq!(vec![2, 3, 4], order.iter().map(|x| *acyclic.node(*x)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_cliques() {
+        // Triangle a-b-c sharing c with the 4-clique c-d-e-f, plus a loose edge f-g.
+        let edges = "a-b\nb-c\na-c\nc-d\nc-e\nc-f\nd-e\nd-f\ne-f\nf-g\n";
+        let graph = Graph::<String>::parse_edges(edges.as_bytes(), '-', false).unwrap();
+        let names = |ids: &[usize]| ids.iter().map(|x| graph.node(*x).as_str()).collect::<Vec<_>>().join(",");
+        let cliques = graph.maximal_cliques();
+        assert_eq!(vec!["a,b,c", "c,d,e,f", "f,g"], cliques.iter().map(|x| names(x)).collect::<Vec<_>>());
+        assert_eq!("c,d,e,f", names(&graph.maximum_clique()));
+        assert_eq!(5, graph.triangles().len());
+        assert_eq!("a,b,c", names(&graph.triangles()[0]));
+        assert!(Graph::<String>::new().maximum_clique().is_empty());
+    }
+
+    #[test]
+    fn test_undirected_edges() {
+        let graph = Graph::<String>::parse_edges("kh-tc\nqp-kh\n\nignored".as_bytes(), '-', false).unwrap();
+        assert_eq!(3, graph.len());
+        let (kh, tc) = (graph.id("kh").unwrap(), graph.id("tc").unwrap());
+        assert!(graph.has_edge(kh, tc) && graph.has_edge(tc, kh));
+        assert_eq!(2, graph.neighbors(kh).len());
+    }
+}
@@ -0,0 +1,240 @@
+use crate::parse::{at_column, at_line, ParseError};
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+/// Position in a grid as `(row, column)`.
+pub type Point = (usize, usize);
+
+/// Rectangular grid stored row by row in one `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Fails if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map(|x| x.len()).unwrap_or(0);
+        if let Some(i) = rows.iter().position(|x| x.len() != width) {
+            return Err(anyhow!("Row {} has {} cells, expected {}", i, rows[i].len(), width));
+        }
+        Ok(Self { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    /// Builds a grid from text lines, converting every char with `cell`. Errors, and lines not as
+    /// long as the first one, are placed on their line and column, counting from the first of `lines`.
+    pub fn from_lines<S: AsRef<str>>(lines: impl IntoIterator<Item = S>, mut cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        for (i, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref();
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(j, ch)| cell(ch).map_err(|x| at_line(at_column(x, j + 1, &ch.to_string()), i + 1, line)))
+                .collect::<Result<Vec<_>>>()?;
+            if let Some(width) = rows.first().map(|x| x.len()).filter(|x| *x != row.len()) {
+                let error = ParseError::new(1, line, format!("expected {width} cells, found {}", row.len()));
+                return Err(at_line(error.into(), i + 1, line));
+            }
+            rows.push(row);
+        }
+        Self::from_rows(rows)
+    }
+
+    /// Reads lines up to the first empty line or the end of the input, converting every char
+    /// with `cell`.
+    pub fn parse<R: BufRead>(reader: R, cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut lines = vec![];
+        for line in reader.lines() {
+            let line = line?;
+            if line.is_empty() {
+                break;
+            }
+            lines.push(line);
+        }
+        Self::from_lines(lines, cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (i, j): Point) -> bool {
+        i < self.height && j < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self.cells[point.0 * self.width + point.1])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if !self.contains(point) {
+            return None;
+        }
+        Some(&mut self.cells[point.0 * self.width + point.1])
+    }
+
+    /// `point` moved by `(rows, columns)`, `None` if it leaves the grid.
+    pub fn offset(&self, (i, j): Point, (di, dj): (isize, isize)) -> Option<Point> {
+        let point = (i.checked_add_signed(di)?, j.checked_add_signed(dj)?);
+        self.contains(point).then_some(point)
+    }
+
+    /// Cell at `point` moved by `(rows, columns)`, `None` if it leaves the grid.
+    pub fn get_offset(&self, point: Point, delta: (isize, isize)) -> Option<&T> {
+        self.offset(point, delta).map(|x| &self[x])
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` from panicking on a grid without columns.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(j).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|j| self.column(j))
+    }
+
+    /// All points in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    /// All cells with their points in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// First point in row-major order that holds `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, x)| *x == value).map(|(point, _)| point)
+    }
+
+    /// All points that hold `value`, in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, x)| *x == value).map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl Grid<char> {
+    /// Reads a grid of chars up to the first empty line or the end of the input.
+    pub fn parse_chars<R: BufRead>(reader: R) -> Result<Self> {
+        Self::parse(reader, Ok)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("Point {:?} is outside of the {}x{} grid", point, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("Point {:?} is outside of the {}x{} grid", point, height, width))
+    }
+}
+
+/// One line per row, cells without separators.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "\
+S.#
+.#E
+
+ignored
+";
+
+    #[test]
+    fn test_parse_and_access() {
+        let grid = Grid::parse_chars(TEST.as_bytes()).unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'#'), grid.get((0, 2)));
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(None, grid.get((0, 3)));
+        assert_eq!(Some(&'E'), grid.get_offset((0, 1), (1, 1)));
+        assert_eq!(None, grid.get_offset((0, 0), (-1, 0)));
+        assert_eq!(Some((1, 2)), grid.find(&'E'));
+        assert_eq!(vec![(0, 2), (1, 1)], grid.find_all(&'#').collect::<Vec<_>>());
+        assert_eq!("S.#\n.#E\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        grid[(1, 1)] = 0;
+        assert_eq!(vec![&[1, 2, 3][..], &[4, 0, 6][..]], grid.rows().collect::<Vec<_>>());
+        assert_eq!(vec![3, 6], grid.column(2).copied().collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(Grid::from_rows(vec![vec![2, 4, 6], vec![8, 0, 12]]).unwrap(), grid.map(|x| x * 2));
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_mapping() {
+        let grid = Grid::parse("#.\n.#\n".as_bytes(), |x| match x {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            x => Err(anyhow!("Unexpected cell '{x}'")),
+        })
+        .unwrap();
+        assert_eq!(vec![(0, 0), (1, 1)], grid.find_all(&true).collect::<Vec<_>>());
+        let error = Grid::parse("##\n#x\n".as_bytes(), |x| if x == '#' { Ok(true) } else { Err(anyhow!("bad")) });
+        assert_eq!("2:2: bad", error.unwrap_err().to_string());
+        let error = Grid::parse_chars("##\n#\n".as_bytes()).unwrap_err();
+        assert_eq!("2:1: expected 2 cells, found 1", error.to_string());
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::*;
+use std::env;
+use std::fs::File;
+use std::io::{stdin, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// Directory with puzzle inputs, one sub-directory per year: `input/<year>/<day>.txt`.
+pub const INPUT_DIR: &str = "input";
+
+/// Environment variable with a path to the input file to use instead of the default one.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Input path that reads the puzzle input from stdin.
+pub const STDIN_PATH: &str = "-";
+
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(INPUT_DIR).join(year.to_string()).join(format!("{day:02}.txt"))
+}
+
+/// Resolves the input file for the day: `input` if given, then `AOC_INPUT`, then
+/// `input/<year>/<day>.txt`.
+pub fn resolve_input(year: u16, day: u8, input: Option<PathBuf>) -> PathBuf {
+    input
+        .or_else(|| env::var_os(INPUT_ENV).map(PathBuf::from))
+        .unwrap_or_else(|| input_path(year, day))
+}
+
+pub fn open_input(year: u16, day: u8) -> Result<Box<dyn BufRead>> {
+    open_path(&resolve_input(year, day, None))
+}
+
+/// Opens the input file at `path`, or stdin if the path is `-`.
+pub fn open_path(path: &Path) -> Result<Box<dyn BufRead>> {
+    if path == Path::new(STDIN_PATH) {
+        return Ok(Box::new(stdin().lock()));
+    }
+    let file = File::open(path).with_context(|| format!("Can not open input file {}", path.display()))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(PathBuf::from("input/2024/07.txt"), input_path(2024, 7));
+        assert_eq!(PathBuf::from("input/2025/07.txt"), input_path(2025, 7));
+        assert_eq!(PathBuf::from("-"), resolve_input(2025, 7, Some(PathBuf::from("-"))));
+    }
+}
@@ -0,0 +1,206 @@
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// Integer types an [`IntervalSet`] can hold.
+pub trait Integer: Copy + Ord + Debug {
+    /// `self + 1`, `None` for the maximum.
+    fn checked_next(self) -> Option<Self>;
+    /// `self - 1`, `None` for the minimum.
+    fn checked_prev(self) -> Option<Self>;
+    /// Number of values in `begin..=end`, saturating for the full range of 128-bit types.
+    fn count(begin: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Integer for $ty {
+                fn checked_next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn checked_prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(begin: Self, end: Self) -> u128 {
+                    (end.abs_diff(begin) as u128).saturating_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Set of integers kept as sorted, disjoint and non-adjacent inclusive ranges, so `1..=3` and
+/// `4..=6` are stored as `1..=6`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value of `range`, merging it with the ranges it overlaps or touches. Empty
+    /// ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut begin, mut end) = range.into_inner();
+        if begin > end {
+            return;
+        }
+        // Ranges before `first` end before `begin - 1`, ranges from `last` start after `end + 1`.
+        let first = self.ranges.partition_point(|(_, x)| x.checked_next().is_some_and(|x| x < begin));
+        let last = self.ranges.partition_point(|(x, _)| end.checked_next().is_none_or(|end| *x <= end));
+        if first < last {
+            begin = begin.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(begin, end)]);
+    }
+
+    /// Binary search over the ranges.
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|(_, end)| *end < value);
+        self.ranges.get(i).is_some_and(|(begin, _)| *begin <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn covered_len(&self) -> u128 {
+        self.ranges.iter().map(|(begin, end)| T::count(*begin, *end)).fold(0, u128::saturating_add)
+    }
+
+    /// The disjoint ranges in increasing order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|(begin, end)| *begin..=*end)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges().chain(other.ranges()).collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (begin, end) = (a.0.max(b.0), a.1.min(b.1));
+            if begin <= end {
+                ranges.push((begin, end));
+            }
+            // The range that ends first can not overlap anything further.
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for &(begin, end) in &self.ranges {
+            let mut begin = Some(begin);
+            while let Some(start) = begin {
+                // Skip the removed ranges that end before the rest of this range.
+                while other.ranges.get(j).is_some_and(|(_, x)| *x < start) {
+                    j += 1;
+                }
+                match other.ranges.get(j) {
+                    Some(&(cut_begin, cut_end)) if cut_begin <= end => {
+                        if start < cut_begin {
+                            ranges.push((start, cut_begin.checked_prev().unwrap()));
+                        }
+                        begin = cut_end.checked_next().filter(|x| *x <= end);
+                    }
+                    _ => {
+                        ranges.push((start, end));
+                        begin = None;
+                    }
+                }
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut ranges = iter
+            .into_iter()
+            .map(|x| x.into_inner())
+            .filter(|(begin, end)| begin <= end)
+            .collect::<Vec<_>>();
+        ranges.sort();
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+        for (begin, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if last.1.checked_next().is_none_or(|x| begin <= x) => last.1 = last.1.max(end),
+                _ => merged.push((begin, end)),
+            }
+        }
+        Self { ranges: merged }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges<T: Integer>(set: &IntervalSet<T>) -> Vec<RangeInclusive<T>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn test_insert_and_contains() {
+        let mut set = IntervalSet::new();
+        set.insert(10..=14);
+        set.insert(3..=5);
+        set.insert(16..=20);
+        set.insert(12..=18);
+        set.insert(6..=6);
+        set.insert(RangeInclusive::new(9, 1));
+        assert_eq!(vec![3..=6, 10..=20], ranges(&set));
+        assert!(set.contains(3) && set.contains(15) && !set.contains(8) && !set.contains(21));
+        assert_eq!(15, set.covered_len());
+        assert_eq!(set, [16..=20, 3..=5, 12..=18, 10..=14, 6..=6].into_iter().collect());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = [1..=3, 4..=6, 10..=20].into_iter().collect::<IntervalSet<i32>>();
+        let b = [-5..=2, 5..=12, 15..=15, 19..=25].into_iter().collect::<IntervalSet<i32>>();
+        assert_eq!(vec![1..=20], ranges(&a.union(&IntervalSet::from_iter([7..=9]))));
+        assert_eq!(vec![-5..=25], ranges(&a.union(&b)));
+        assert_eq!(vec![1..=2, 5..=6, 10..=12, 15..=15, 19..=20], ranges(&a.intersection(&b)));
+        assert_eq!(vec![3..=4, 13..=14, 16..=18], ranges(&a.difference(&b)));
+        assert_eq!(vec![-5..=0, 7..=9, 21..=25], ranges(&b.difference(&a)));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_type_limits() {
+        let mut set = IntervalSet::new();
+        set.insert(250u8..=255);
+        set.insert(0..=3);
+        set.insert(4..=249);
+        assert_eq!(vec![0..=255], ranges(&set));
+        assert_eq!(256, set.covered_len());
+        let cut = set.difference(&IntervalSet::from_iter([0..=0, 255..=255]));
+        assert_eq!(vec![1..=254], ranges(&cut));
+    }
+}
@@ -1,7 +1,34 @@
+pub mod answer;
+pub mod answers;
+pub mod cli;
+pub mod computer;
+pub mod days;
+pub mod dot;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
 pub mod maze;
+pub mod netlist;
+pub mod params;
+pub mod parse;
+pub mod render;
+pub mod search;
+pub mod sections;
+pub mod solution;
+pub mod trie;
+pub mod union_find;
 
-pub fn start_day(day: &str) {
-    println!("Advent of Code 2024 - Day {:0>2}", day);
+pub use answer::Answer;
+pub use cli::run_day;
+pub use graph::Graph;
+pub use grid::Grid;
+pub use input::open_input;
+pub use params::Params;
+pub use solution::{run_puzzle, Puzzle, Solution};
+
+pub fn start_day(year: u16, day: u8) {
+    println!("Advent of Code {} - Day {:02}", year, day);
 }
 
 // Additional common functions
@@ -12,6 +39,6 @@ mod tests {
 
     #[test]
     fn it_works() {
-        start_day("00");
+        start_day(2025, 0);
     }
 }
@@ -1,30 +1,105 @@
-use std::collections::HashSet;
+use anyhow::Context;
 use std::io::BufRead;
 
-pub type Point = (usize, usize);
+pub use crate::grid::Point;
 
-pub type Grid = Vec<Vec<char>>;
+pub type Grid = crate::grid::Grid<char>;
 
-pub fn read_map<R: BufRead>(reader: R) -> Grid {
-    reader
-        .lines()
-        .flatten()
-        .map(|x| x.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>()
+/// Move from a point as `(rows, columns)`.
+pub type Offset = (isize, isize);
+
+/// Right, down, left and up, in the order of [`Direction`].
+pub const CARDINAL: [Offset; 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+pub const DIAGONAL: [Offset; 4] = [(1, 1), (1, -1), (-1, -1), (-1, 1)];
+
+/// Cardinal and diagonal moves, clockwise from the right.
+pub const ALL_AROUND: [Offset; 8] = [(0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1), (-1, 0), (-1, 1)];
+
+/// Hex neighbors of a point in an even row, when odd rows are shifted half a cell to the right.
+pub const HEX_EVEN_ROW: [Offset; 6] = [(0, 1), (1, 0), (1, -1), (0, -1), (-1, -1), (-1, 0)];
+
+/// Hex neighbors of a point in an odd row, when odd rows are shifted half a cell to the right.
+pub const HEX_ODD_ROW: [Offset; 6] = [(0, 1), (1, 1), (1, 0), (0, -1), (-1, 0), (-1, 1)];
+
+/// Area of `height` rows and `width` columns that neighbors are looked up in. With `wrap`,
+/// opposite edges are adjacent, like on a torus.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub height: usize,
+    pub width: usize,
+    pub wrap: bool,
 }
-pub fn find_start_end_point(grid: &Grid) -> (Point, Point) {
-    let mut start_point = None;
-    let mut end_point = None;
-    for i in 0..grid.len() {
-        for j in 0..grid[0].len() {
-            match grid[i][j] {
-                'E' => end_point = Some((i, j)),
-                'S' => start_point = Some((i, j)),
-                _ => {},
+
+impl Bounds {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self { height, width, wrap: false }
+    }
+
+    pub fn of<T>(grid: &crate::grid::Grid<T>) -> Self {
+        Self::new(grid.height(), grid.width())
+    }
+
+    pub fn wrapping(self) -> Self {
+        Self { wrap: true, ..self }
+    }
+
+    pub fn contains(&self, (i, j): Point) -> bool {
+        i < self.height && j < self.width
+    }
+
+    /// `point` moved by `offset`, `None` if it leaves the area.
+    pub fn step(&self, (i, j): Point, (di, dj): Offset) -> Option<Point> {
+        if self.wrap {
+            if self.height == 0 || self.width == 0 {
+                return None;
             }
+            let i = (i as isize + di).rem_euclid(self.height as isize) as usize;
+            let j = (j as isize + dj).rem_euclid(self.width as isize) as usize;
+            return Some((i, j));
         }
+        let point = (i.checked_add_signed(di)?, j.checked_add_signed(dj)?);
+        self.contains(point).then_some(point)
+    }
+
+    /// Points at `offsets` from `point` that are inside the area.
+    pub fn neighbors<'a>(self, point: Point, offsets: &'a [Offset]) -> impl Iterator<Item = Point> + 'a {
+        offsets.iter().filter_map(move |x| self.step(point, *x))
+    }
+
+    pub fn cardinal_neighbors(self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbors(point, &CARDINAL)
+    }
+
+    pub fn diagonal_neighbors(self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbors(point, &DIAGONAL)
+    }
+
+    pub fn all_neighbors(self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbors(point, &ALL_AROUND)
+    }
+
+    pub fn hex_neighbors(self, point: Point) -> impl Iterator<Item = Point> {
+        let offsets: &'static [Offset] = if point.0.is_multiple_of(2) { &HEX_EVEN_ROW } else { &HEX_ODD_ROW };
+        self.neighbors(point, offsets)
+    }
+
+    /// `point` followed by the points reached by repeating `offset`, until one leaves the area.
+    /// Never ends in a wrapping area.
+    pub fn ray(self, point: Point, offset: Offset) -> impl Iterator<Item = Point> {
+        std::iter::successors(Some(point), move |x| self.step(*x, offset))
     }
-    (start_point.unwrap(), end_point.unwrap())
+}
+
+pub fn read_map<R: BufRead>(reader: R) -> anyhow::Result<Grid> {
+    Grid::parse_chars(reader)
+}
+
+/// Points of the start `S` and the end `E`, fails if the map lacks one of them.
+pub fn find_start_end_point(grid: &Grid) -> anyhow::Result<(Point, Point)> {
+    let start = grid.find(&'S').context("No start 'S' on the map")?;
+    let end = grid.find(&'E').context("No end 'E' on the map")?;
+    Ok((start, end))
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -36,6 +111,8 @@ pub enum Direction {
 }
 
 impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::East, Direction::South, Direction::West, Direction::North];
+
     pub fn rotate_clockwise(&self) -> Direction {
         match self {
             Direction::East => Direction::South,
@@ -54,6 +131,11 @@ impl Direction {
         }
     }
 
+    pub fn offset(&self) -> Offset {
+        let (di, dj) = self.delta();
+        (di as isize, dj as isize)
+    }
+
     pub fn delta(&self) -> (i32, i32) {
         match self {
             Direction::East => (0, 1),
@@ -63,27 +145,43 @@ impl Direction {
         }
     }
 }
-pub fn next_position(point: &Point, direction: &Direction) -> anyhow::Result<Point> {
-    let delta = direction.delta();
-    anyhow::Ok((
-        usize::try_from(point.0 as i32 + delta.0)?,
-        usize::try_from(point.1 as i32 + delta.1)?,
-    ))
-}
 
-pub fn prev_position(point: &Point, direction: &Direction) -> anyhow::Result<Point> {
-    let delta = direction.delta();
-    anyhow::Ok((
-        usize::try_from(point.0 as i32 - delta.0)?,
-        usize::try_from(point.1 as i32 - delta.1)?,
-    ))
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-pub fn print_grid(grid: &Grid) {
-    for i in 0..grid.len() {
-        for j in 0..grid[0].len() {
-            print!("{}", grid[i][j]);
-        }
-        print!("\n");
+    #[test]
+    fn test_neighbors() {
+        let bounds = Bounds::new(3, 4);
+        assert_eq!(vec![(0, 1), (1, 0)], bounds.cardinal_neighbors((0, 0)).collect::<Vec<_>>());
+        assert_eq!(vec![(1, 1)], bounds.diagonal_neighbors((0, 0)).collect::<Vec<_>>());
+        assert_eq!(8, bounds.all_neighbors((1, 1)).count());
+        assert_eq!(3, bounds.all_neighbors((2, 3)).count());
+        assert_eq!(
+            vec![(1, 1), (2, 1), (2, 0), (0, 0), (0, 1)],
+            bounds.hex_neighbors((1, 0)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let bounds = Bounds::new(3, 4).wrapping();
+        assert_eq!(vec![(0, 1), (1, 0), (0, 3), (2, 0)], bounds.cardinal_neighbors((0, 0)).collect::<Vec<_>>());
+        assert_eq!(Some((0, 0)), bounds.step((2, 3), (1, 1)));
+    }
+
+    #[test]
+    fn test_ray() {
+        let bounds = Bounds::new(3, 4);
+        assert_eq!(vec![(0, 1), (1, 2), (2, 3)], bounds.ray((0, 1), (1, 1)).collect::<Vec<_>>());
+        assert_eq!(bounds.step((0, 0), Direction::South.offset()), Some((1, 0)));
+    }
+
+    #[test]
+    fn test_start_end_point() {
+        let grid = read_map("#S.\n.#E\n".as_bytes()).unwrap();
+        assert_eq!(((0, 1), (1, 2)), find_start_end_point(&grid).unwrap());
+        let grid = read_map("#S.\n.#.\n".as_bytes()).unwrap();
+        assert_eq!("No end 'E' on the map", find_start_end_point(&grid).unwrap_err().to_string());
     }
 }
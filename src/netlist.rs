@@ -0,0 +1,248 @@
+use crate::parse::ParseError;
+use crate::Graph;
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Operation {
+    And,
+    Or,
+    Xor,
+}
+
+impl Operation {
+    pub fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Operation::And => a & b,
+            Operation::Or => a | b,
+            Operation::Xor => a ^ b,
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "AND" => Ok(Operation::And),
+            "OR" => Ok(Operation::Or),
+            "XOR" => Ok(Operation::Xor),
+            _ => Err(anyhow!("Unknown operation \"{s}\"")),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::And => write!(f, "AND"),
+            Operation::Or => write!(f, "OR"),
+            Operation::Xor => write!(f, "XOR"),
+        }
+    }
+}
+
+/// Gate line like `x00 AND y00 -> z00`. The inputs are kept in name order, so `y00 AND x00` is
+/// the same gate.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Gate {
+    pub inputs: [String; 2],
+    pub operation: Operation,
+    pub output: String,
+}
+
+impl Gate {
+    pub fn new(a: &str, operation: Operation, b: &str, output: &str) -> Self {
+        let mut inputs = [a.to_string(), b.to_string()];
+        inputs.sort();
+        Self { inputs, operation, output: output.to_string() }
+    }
+
+    pub fn has_input(&self, wire: &str) -> bool {
+        self.inputs.iter().any(|x| x == wire)
+    }
+}
+
+impl FromStr for Gate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parts = s.split(' ').collect::<Vec<_>>();
+        let [a, operation, b, "->", output] = parts[..] else {
+            return Err(ParseError::expected(1, "a gate like 'x00 AND y00 -> z00'", s).into());
+        };
+        let operation = operation
+            .parse()
+            .map_err(|_| ParseError::expected(a.len() + 2, "AND, OR or XOR", operation))?;
+        Ok(Gate::new(a, operation, b, output))
+    }
+}
+
+impl Display for Gate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {} -> {}", self.inputs[0], self.operation, self.inputs[1], self.output)
+    }
+}
+
+/// Gates wired into a circuit and sorted so that every gate comes after the gates driving its
+/// inputs. Building it fails for wires driven by more than one gate and for loops, so evaluating
+/// always terminates. Wires that no gate drives are the inputs of the circuit.
+#[derive(Clone, Debug)]
+pub struct Netlist {
+    gates: Vec<Gate>,
+    /// Wire graph, with an edge from every gate input to the gate output.
+    wires: Graph<String>,
+    /// Gate that drives every wire, by wire id.
+    drivers: Vec<Option<usize>>,
+    /// Gates in evaluation order.
+    order: Vec<usize>,
+}
+
+impl Netlist {
+    pub fn new(gates: Vec<Gate>) -> Result<Self> {
+        let mut wires = Graph::new();
+        for gate in &gates {
+            for input in &gate.inputs {
+                wires.add_edge(input.clone(), gate.output.clone());
+            }
+        }
+        let mut drivers = vec![None; wires.len()];
+        for (i, gate) in gates.iter().enumerate() {
+            let output = wires.id(&gate.output).unwrap();
+            if let Some(other) = drivers[output].replace(i) {
+                return Err(anyhow!("Wire {} is driven by both \"{}\" and \"{}\"", gate.output, gates[other], gate));
+            }
+        }
+        let order = wires.topological_sort()?.into_iter().filter_map(|x| drivers[x]).collect();
+        Ok(Self { gates, wires, drivers, order })
+    }
+
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// Every wire name, by wire id.
+    pub fn wires(&self) -> impl Iterator<Item = &str> + '_ {
+        self.wires.ids().map(|x| self.wires.node(x).as_str())
+    }
+
+    pub fn id(&self, wire: &str) -> Option<usize> {
+        self.wires.id(wire)
+    }
+
+    /// Gate that drives `wire`, `None` for inputs and unknown wires.
+    pub fn driver(&self, wire: &str) -> Option<&Gate> {
+        self.id(wire).and_then(|x| self.drivers[x]).map(|x| &self.gates[x])
+    }
+
+    /// Gates that read `wire`.
+    pub fn readers(&self, wire: &str) -> Vec<&Gate> {
+        self.gates.iter().filter(|x| x.has_input(wire)).collect()
+    }
+
+    /// Wires that no gate drives, in name order.
+    pub fn inputs(&self) -> Vec<&str> {
+        let mut inputs = self
+            .wires
+            .ids()
+            .filter(|x| self.drivers[*x].is_none())
+            .map(|x| self.wires.node(x).as_str())
+            .collect::<Vec<_>>();
+        inputs.sort();
+        inputs
+    }
+
+    /// Wires named `prefix` and a number, like `z00`, from the lowest bit up.
+    pub fn bits(&self, prefix: char) -> Vec<&str> {
+        let mut bits = self
+            .wires()
+            .filter(|x| bit_index(x, prefix).is_some())
+            .collect::<Vec<_>>();
+        bits.sort_by_key(|x| (x.len(), *x));
+        bits
+    }
+
+    /// Values of all wires by wire id, with `input` giving the value of every input wire.
+    pub fn evaluate(&self, input: impl Fn(&str) -> Option<bool>) -> Result<Vec<bool>> {
+        let mut values = vec![false; self.wires.len()];
+        for (id, value) in values.iter_mut().enumerate() {
+            if self.drivers[id].is_none() {
+                let wire = self.wires.node(id);
+                *value = input(wire).context(format!("Wire {wire} is not driven and has no value"))?;
+            }
+        }
+        for gate in self.order.iter().map(|x| &self.gates[*x]) {
+            let [a, b] = gate.inputs.each_ref().map(|x| values[self.wires.id(x).unwrap()]);
+            values[self.wires.id(&gate.output).unwrap()] = gate.operation.apply(a, b);
+        }
+        Ok(values)
+    }
+
+    /// Number made of the bits of the wires named `prefix` in `values`.
+    pub fn number(&self, values: &[bool], prefix: char) -> u64 {
+        self.bits(prefix)
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| values[self.wires.id(**x).unwrap()])
+            .fold(0, |acc, (i, _)| acc | 1 << i)
+    }
+
+    /// Evaluates the circuit with the `x` and `y` wires set to the bits of `x` and `y` and returns
+    /// the number on the `z` wires. Any other wire without a driver is an error.
+    pub fn add(&self, x: u64, y: u64) -> Result<u64> {
+        let bit = |wire: &str, prefix: char, value: u64| bit_index(wire, prefix).map(|i| i < 64 && value >> i & 1 == 1);
+        let values = self.evaluate(|wire| bit(wire, 'x', x).or_else(|| bit(wire, 'y', y)))?;
+        Ok(self.number(&values, 'z'))
+    }
+}
+
+/// Number of a wire named `prefix` followed by digits only, like 5 for `x05`.
+fn bit_index(wire: &str, prefix: char) -> Option<u32> {
+    wire.strip_prefix(prefix).filter(|x| !x.is_empty() && x.bytes().all(|x| x.is_ascii_digit()))?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn netlist(gates: &str) -> Result<Netlist> {
+        Netlist::new(gates.lines().map(str::parse).collect::<Result<Vec<Gate>>>()?)
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let gates = "\
+x01 AND y01 -> c01
+y00 XOR x00 -> z00
+x00 AND y00 -> c00
+c00 XOR s01 -> z01
+x01 XOR y01 -> s01
+c00 AND s01 -> d01
+c01 OR d01 -> z02";
+        let netlist = netlist(gates).unwrap();
+        assert_eq!(vec!["x00", "x01", "y00", "y01"], netlist.inputs());
+        assert_eq!(vec!["z00", "z01", "z02"], netlist.bits('z'));
+        assert_eq!("x00 XOR y00 -> z00", netlist.driver("z00").unwrap().to_string());
+        assert_eq!(2, netlist.readers("c00").len());
+        for (x, y) in [(0, 0), (1, 2), (3, 3), (2, 3)] {
+            assert_eq!(x + y, netlist.add(x, y).unwrap());
+        }
+        let values = netlist.evaluate(|x| Some(x == "x00")).unwrap();
+        assert_eq!(1, netlist.number(&values, 'z'));
+        let error = netlist.evaluate(|x| (x != "y00").then_some(true)).unwrap_err();
+        assert_eq!("Wire y00 is not driven and has no value", error.to_string());
+        let error = self::netlist("x00 AND xkm -> z00\ny00 OR x00 -> z01").unwrap().add(1, 1).unwrap_err();
+        assert_eq!("Wire xkm is not driven and has no value", error.to_string());
+    }
+
+    #[test]
+    fn test_broken_wiring() {
+        let error = netlist("x00 AND y00 -> z00\nx00 OR y00 -> z00").unwrap_err();
+        assert_eq!("Wire z00 is driven by both \"x00 AND y00 -> z00\" and \"x00 OR y00 -> z00\"", error.to_string());
+        assert!(netlist("a AND x00 -> b\nb OR y00 -> a").unwrap_err().to_string().starts_with("Cycle"));
+        let error = "x00 NAND y00 -> z00".parse::<Gate>().unwrap_err();
+        assert_eq!("column 5: expected AND, OR or XOR, found 'NAND'", error.to_string());
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Puzzle parameters that the statement gives apart from the input, like the size of a room or
+/// the number of steps. Days default to the values of the real puzzle, examples pass their own
+/// ones as `key=value` words, like `size=7 bytes=12`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: &str, value: &str) -> Self {
+        self.values.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Adds one `key=value` word.
+    pub fn insert(&mut self, word: &str) -> Result<()> {
+        let (key, value) = word
+            .split_once('=')
+            .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+            .context(format!("Expected a parameter as key=value, got \"{word}\""))?;
+        self.values.insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    /// Value of `key`, `default` if it is not given.
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T> {
+        match self.values.get(key) {
+            None => Ok(default),
+            Some(value) => value.parse().ok().context(format!("Wrong value \"{value}\" of parameter {key}")),
+        }
+    }
+
+    /// Fails on a parameter that is not one of `keys`, so a misspelled one is not silently
+    /// replaced by its default.
+    pub fn expect_only(&self, keys: &[&str]) -> Result<()> {
+        match self.values.keys().find(|x| !keys.contains(&x.as_str())) {
+            None => Ok(()),
+            Some(key) => Err(anyhow!("Unknown parameter {key}, expected one of: {}", keys.join(", "))),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl FromStr for Params {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut params = Self::new();
+        for word in s.split_whitespace() {
+            params.insert(word)?;
+        }
+        Ok(params)
+    }
+}
+
+/// `key=value` words separated by spaces, the form [`Params::from_str`] reads.
+impl Display for Params {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, (key, value)) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{key}={value}")?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        let params = "size=7 bytes=12".parse::<Params>().unwrap();
+        assert_eq!(Params::new().with("bytes", "12").with("size", "7"), params);
+        assert_eq!(7, params.get("size", 71).unwrap());
+        assert_eq!(1000, params.get("pairs", 1000).unwrap());
+        assert!(params.get::<usize>("size", 0).is_ok() && params.get::<bool>("size", false).is_err());
+        assert_eq!("bytes=12 size=7", params.to_string());
+        assert!(params.expect_only(&["size", "bytes"]).is_ok());
+        assert_eq!("Unknown parameter size, expected one of: bytes", params.expect_only(&["bytes"]).unwrap_err().to_string());
+        assert!("size".parse::<Params>().is_err());
+        assert!("=7".parse::<Params>().is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use anyhow::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Malformed input, displayed as `input/2025/01.txt:17:1: expected L or R, found 'X'`.
+///
+/// Parsers of a single line only know the column, the line and the file are filled in by
+/// [`parse_lines`] and [`in_file`] on the way up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    /// Line and column, starting with 1, `0` while unknown.
+    pub line: usize,
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, text: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { file: None, line: 0, column, text: text.into(), message: message.into() }
+    }
+
+    /// `expected <what>, found '<text>'`.
+    pub fn expected(column: usize, what: &str, text: impl Into<String>) -> Self {
+        let text = text.into();
+        let message = format!("expected {what}, found '{text}'");
+        Self::new(column, text, message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if self.line > 0 {
+            write!(f, "{}:{}: ", self.line, self.column)?;
+        } else if self.column > 0 {
+            write!(f, "column {}: ", self.column)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Places `error` on `line`. A `ParseError` inside gets the line number, any other error becomes
+/// a `ParseError` for the whole line.
+pub fn at_line(mut error: Error, line: usize, text: &str) -> Error {
+    match error.downcast_mut::<ParseError>() {
+        Some(parse_error) => {
+            parse_error.line = line;
+            error
+        }
+        None => {
+            let message = format!("{error:#}");
+            let mut parse_error = ParseError::new(1, text, message);
+            parse_error.line = line;
+            parse_error.into()
+        }
+    }
+}
+
+/// Places `error` at `column` of a line. A `ParseError` inside keeps its own column unless it is
+/// still unknown, any other error becomes a `ParseError` for `text`.
+pub fn at_column(mut error: Error, column: usize, text: &str) -> Error {
+    if let Some(parse_error) = error.downcast_mut::<ParseError>() {
+        if parse_error.column == 0 {
+            parse_error.column = column;
+        }
+        return error;
+    }
+    ParseError::new(column, text, format!("{error:#}")).into()
+}
+
+/// Adds `path` to a `ParseError` inside `error`, other errors are returned as they are.
+pub fn in_file(mut error: Error, path: &Path) -> Error {
+    if let Some(parse_error) = error.downcast_mut::<ParseError>() {
+        parse_error.file = Some(path.to_path_buf());
+    }
+    error
+}
+
+/// Every line of the input. Unlike `lines().flatten()`, a read error fails instead of being
+/// skipped, which would loop forever on some readers.
+pub fn read_lines<R: BufRead>(reader: R) -> anyhow::Result<Vec<String>> {
+    Ok(reader.lines().collect::<std::io::Result<Vec<_>>>()?)
+}
+
+/// Parses every line with `f`, errors are placed on their line with [`at_line`].
+pub fn parse_lines<R: BufRead, T>(reader: R, mut f: impl FnMut(&str) -> anyhow::Result<T>) -> anyhow::Result<Vec<T>> {
+    let mut values = vec![];
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        values.push(f(&line).map_err(|x| at_line(x, i + 1, &line))?);
+    }
+    Ok(values)
+}
+
+/// `text` at `column` parsed as a number.
+pub fn parse_number<T: FromStr>(text: &str, column: usize) -> Result<T, ParseError> {
+    text.parse().map_err(|_| ParseError::expected(column, "a number", text))
+}
+
+/// Numbers of `text` split by `separator`, `column` is where `text` starts in its line.
+pub fn parse_numbers<T: FromStr>(text: &str, separator: &str, mut column: usize) -> Result<Vec<T>, ParseError> {
+    let mut numbers = vec![];
+    for part in text.split(separator) {
+        numbers.push(parse_number(part, column)?);
+        column += part.len() + separator.len();
+    }
+    Ok(numbers)
+}
+
+/// Two numbers of `text` split by `separator`, like `3-5` or `7,1`.
+pub fn parse_pair<T: FromStr>(text: &str, separator: &str, column: usize) -> Result<(T, T), ParseError> {
+    let (first, second) = text
+        .split_once(separator)
+        .ok_or_else(|| ParseError::expected(column, &format!("two numbers separated by '{separator}'"), text))?;
+    Ok((parse_number(first, column)?, parse_number(second, column + first.len() + separator.len())?))
+}
+
+/// Value of a decimal digit at `column`.
+pub fn parse_digit(ch: char, column: usize) -> Result<u8, ParseError> {
+    ch.to_digit(10).map(|x| x as u8).ok_or_else(|| ParseError::expected(column, "a digit", ch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{anyhow, Context};
+
+    fn parse_rotation(line: &str) -> anyhow::Result<i32> {
+        let sign = match line.chars().next() {
+            Some('L') => -1,
+            Some('R') => 1,
+            _ => return Err(ParseError::expected(1, "L or R", line.get(..1).unwrap_or_default()).into()),
+        };
+        Ok(sign * parse_number::<i32>(&line[1..], 2)?)
+    }
+
+    #[test]
+    fn test_error_location() {
+        let input = "L68\nR30\nX12\n";
+        let error = parse_lines(input.as_bytes(), parse_rotation).unwrap_err();
+        let error = in_file(error, Path::new("input/2025/01.txt"));
+        assert_eq!("input/2025/01.txt:3:1: expected L or R, found 'X'", error.to_string());
+
+        let error = parse_lines("L1\nRx\n".as_bytes(), parse_rotation).unwrap_err();
+        assert_eq!("2:2: expected a number, found 'x'", error.to_string());
+        assert_eq!(vec![-68, 30], parse_lines("L68\nR30".as_bytes(), parse_rotation).unwrap());
+    }
+
+    #[test]
+    fn test_other_errors_cover_the_line() {
+        let error = parse_lines("ok\nbad\n".as_bytes(), |x| match x {
+            "ok" => Ok(()),
+            _ => Err(anyhow!("not ok")).context("Checking"),
+        });
+        let error = error.unwrap_err();
+        assert_eq!("2:1: Checking: not ok", error.to_string());
+        assert_eq!("bad", error.downcast_ref::<ParseError>().unwrap().text);
+        assert_eq!(Err(ParseError::expected(4, "a digit", "z")), parse_digit('z', 4));
+        assert_eq!(Ok(vec![7, 6, 4]), parse_numbers::<u8>("7 6 4", " ", 1));
+        assert_eq!(Err(ParseError::expected(5, "a number", "x")), parse_numbers::<u8>("7 6 x", " ", 1));
+        assert_eq!(Ok((3, 15)), parse_pair::<u8>("3-15", "-", 1));
+        assert_eq!(Err(ParseError::expected(5, "a number", "1x")), parse_pair::<u8>("3-1x", "-", 3));
+        let error = at_column(parse_digit('z', 0).unwrap_err().into(), 3, "z");
+        assert_eq!("column 3: expected a digit, found 'z'", error.to_string());
+        let error = parse_pair::<u8>("3", "-", 1).unwrap_err();
+        assert_eq!("column 1: expected two numbers separated by '-', found '3'", error.to_string());
+    }
+}
@@ -0,0 +1,304 @@
+use crate::grid::{Grid, Point};
+use anyhow::*;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, DynamicImage, Frame, Rgb, RgbImage};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+pub const WHITE: Rgb<u8> = Rgb([255, 255, 255]);
+pub const BLACK: Rgb<u8> = Rgb([0, 0, 0]);
+pub const GRAY: Rgb<u8> = Rgb([160, 160, 160]);
+pub const GREEN: Rgb<u8> = Rgb([6, 64, 43]);
+pub const RED: Rgb<u8> = Rgb([220, 40, 40]);
+pub const ORANGE: Rgb<u8> = Rgb([255, 165, 0]);
+pub const BLUE: Rgb<u8> = Rgb([40, 90, 220]);
+
+/// Color of every cell value, cells without a color of their own get the default one.
+#[derive(Clone, Debug)]
+pub struct Palette<T> {
+    colors: Vec<(T, Rgb<u8>)>,
+    default: Rgb<u8>,
+}
+
+impl<T: PartialEq> Palette<T> {
+    pub fn new(default: Rgb<u8>) -> Self {
+        Self { colors: vec![], default }
+    }
+
+    pub fn with(mut self, cell: T, color: Rgb<u8>) -> Self {
+        self.colors.push((cell, color));
+        self
+    }
+
+    pub fn color(&self, cell: &T) -> Rgb<u8> {
+        self.colors.iter().find(|(x, _)| x == cell).map_or(self.default, |(_, color)| *color)
+    }
+}
+
+/// Points drawn over the cells of a grid, like a path or the visited cells. Later points win.
+#[derive(Clone, Debug, Default)]
+pub struct Overlay {
+    points: Vec<(Point, Rgb<u8>)>,
+}
+
+impl Overlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, points: impl IntoIterator<Item = Point>, color: Rgb<u8>) -> Self {
+        self.points.extend(points.into_iter().map(|x| (x, color)));
+        self
+    }
+}
+
+/// How animations are recorded: `fps` frames per second, one frame every `every` steps and at
+/// most `max_frames` frames.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Recording {
+    fps: u32,
+    every: usize,
+    max_frames: usize,
+}
+
+impl Recording {
+    /// GIF delays are in hundredths of a second, so `fps` has to be between 1 and 100.
+    pub fn new(fps: u32, every: usize, max_frames: usize) -> Result<Self> {
+        if !(1..=100).contains(&fps) {
+            return Err(anyhow!("Frame rate has to be between 1 and 100, got {fps}"));
+        }
+        if every == 0 || max_frames == 0 {
+            return Err(anyhow!("Frame skipping and the frame count have to be at least 1"));
+        }
+        Ok(Self { fps, every, max_frames })
+    }
+
+    pub fn fps(&self) -> u32 {
+        self.fps
+    }
+
+    pub fn every(&self) -> usize {
+        self.every
+    }
+
+    pub fn max_frames(&self) -> usize {
+        self.max_frames
+    }
+}
+
+impl Default for Recording {
+    fn default() -> Self {
+        Self { fps: 10, every: 1, max_frames: 300 }
+    }
+}
+
+/// Frames of a simulation, recorded step by step.
+#[derive(Clone, Debug)]
+pub struct Animation {
+    recording: Recording,
+    steps: usize,
+    /// Whether the frame of the last step was kept.
+    last_kept: bool,
+    frames: Vec<RgbImage>,
+}
+
+impl Animation {
+    pub fn new(recording: Recording) -> Self {
+        Self { recording, steps: 0, last_kept: false, frames: vec![] }
+    }
+
+    /// Counts a step of the simulation. `draw` is only called for the steps that are kept: one
+    /// every `every` steps, starting with the first, until the animation is full.
+    pub fn record(&mut self, draw: impl FnOnce() -> RgbImage) {
+        if self.keep_next() {
+            self.frames.push(draw());
+        }
+    }
+
+    /// [`Animation::record`] for frames that can fail to draw.
+    pub fn try_record(&mut self, draw: impl FnOnce() -> Result<RgbImage>) -> Result<()> {
+        if self.keep_next() {
+            self.frames.push(draw()?);
+        }
+        Ok(())
+    }
+
+    fn keep_next(&mut self) -> bool {
+        self.last_kept = self.steps.is_multiple_of(self.recording.every) && !self.is_full();
+        self.steps += 1;
+        self.last_kept
+    }
+
+    /// Keeps the final state of the simulation if its step was skipped, in place of the last frame
+    /// when the animation is full.
+    pub fn finish(&mut self, draw: impl FnOnce() -> RgbImage) {
+        if self.last_kept || self.steps == 0 {
+            return;
+        }
+        if self.is_full() {
+            self.frames.pop();
+        }
+        self.frames.push(draw());
+        self.last_kept = true;
+    }
+
+    /// Simulations can stop early once no more frames are kept.
+    pub fn is_full(&self) -> bool {
+        self.frames.len() >= self.recording.max_frames
+    }
+
+    pub fn frames(&self) -> &[RgbImage] {
+        &self.frames
+    }
+
+    /// Encodes the frames as a GIF that loops forever.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let file = File::create(path).with_context(|| format!("Can not create {}", path.display()))?;
+        let mut encoder = GifEncoder::new_with_speed(BufWriter::new(file), 10);
+        encoder.set_repeat(Repeat::Infinite)?;
+        let delay = Delay::from_numer_denom_ms(1000, self.recording.fps);
+        let frames = self
+            .frames
+            .iter()
+            .map(|x| Frame::from_parts(DynamicImage::from(x.clone()).into_rgba8(), 0, 0, delay));
+        encoder.encode_frames(frames).with_context(|| format!("Can not save {}", path.display()))
+    }
+}
+
+/// Draws grids as PNG images and animations as GIFs into one directory, every cell as a square of
+/// `scale` pixels.
+#[derive(Clone, Debug)]
+pub struct Renderer {
+    directory: PathBuf,
+    scale: u32,
+    recording: Recording,
+}
+
+impl Renderer {
+    /// Fails for a scale of 0.
+    pub fn new(directory: impl Into<PathBuf>, scale: u32) -> Result<Self> {
+        if scale == 0 {
+            return Err(anyhow!("Scale has to be at least 1"));
+        }
+        Ok(Self { directory: directory.into(), scale, recording: Recording::default() })
+    }
+
+    pub fn with_recording(mut self, recording: Recording) -> Self {
+        self.recording = recording;
+        self
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Image of `grid` in the colors of `palette`, with `overlay` on top. Overlay points outside
+    /// of the grid are skipped.
+    pub fn draw<T: PartialEq>(&self, grid: &Grid<T>, palette: &Palette<T>, overlay: Option<&Overlay>) -> RgbImage {
+        let mut image = RgbImage::new(grid.width() as u32 * self.scale, grid.height() as u32 * self.scale);
+        for (point, cell) in grid.iter() {
+            self.fill(&mut image, point, palette.color(cell));
+        }
+        for (point, color) in overlay.into_iter().flat_map(|x| &x.points) {
+            if grid.contains(*point) {
+                self.fill(&mut image, *point, *color);
+            }
+        }
+        image
+    }
+
+    /// Saves `image` as `<directory>/<name>.png`, creating the directory, and returns the path.
+    pub fn save(&self, image: &RgbImage, name: &str) -> Result<PathBuf> {
+        let path = self.path(name, "png")?;
+        image.save(&path).with_context(|| format!("Can not save {}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Empty animation with the recording settings of the renderer.
+    pub fn animation(&self) -> Animation {
+        Animation::new(self.recording)
+    }
+
+    /// Saves `animation` as `<directory>/<name>.gif`, creating the directory, and returns the path.
+    pub fn save_animation(&self, animation: &Animation, name: &str) -> Result<PathBuf> {
+        let path = self.path(name, "gif")?;
+        animation.save(&path)?;
+        Ok(path)
+    }
+
+    fn path(&self, name: &str, extension: &str) -> Result<PathBuf> {
+        fs::create_dir_all(&self.directory).with_context(|| format!("Can not create {}", self.directory.display()))?;
+        Ok(self.directory.join(format!("{name}.{extension}")))
+    }
+
+    fn fill(&self, image: &mut RgbImage, (i, j): Point, color: Rgb<u8>) {
+        for y in 0..self.scale {
+            for x in 0..self.scale {
+                image.put_pixel(j as u32 * self.scale + x, i as u32 * self.scale + y, color);
+            }
+        }
+    }
+}
+
+/// Grid of `height` rows and `width` columns that is `true` at `points`, for puzzles that only
+/// have a set of points. Points outside of it are skipped.
+pub fn point_grid(height: usize, width: usize, points: impl IntoIterator<Item = Point>) -> Grid<bool> {
+    let mut grid = Grid::new(width, height, false);
+    for point in points {
+        if let Some(cell) = grid.get_mut(point) {
+            *cell = true;
+        }
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw() {
+        let grid = Grid::from_lines(["#.", ".."], Ok).unwrap();
+        let renderer = Renderer::new("output/test", 2).unwrap();
+        let palette = Palette::new(WHITE).with('#', BLACK);
+        let overlay = Overlay::new().with([(1, 1), (5, 5)], RED);
+        let image = renderer.draw(&grid, &palette, Some(&overlay));
+        assert_eq!((4, 4), image.dimensions());
+        assert_eq!((BLACK, BLACK, WHITE), (image[(0, 0)], image[(1, 1)], image[(2, 1)]));
+        assert_eq!((WHITE, RED, RED), (image[(1, 2)], image[(2, 2)], image[(3, 3)]));
+        assert_eq!(WHITE, renderer.draw(&grid, &palette, None)[(3, 3)]);
+        assert!(Renderer::new("output", 0).is_err());
+    }
+
+    #[test]
+    fn test_animation() {
+        let recording = Recording::new(20, 3, 3).unwrap();
+        let mut animation = Animation::new(recording);
+        let mut drawn = vec![];
+        for step in 0..10u8 {
+            animation.record(|| {
+                drawn.push(step);
+                RgbImage::from_pixel(2, 1, Rgb([step, 0, 0]))
+            });
+        }
+        assert_eq!(vec![0, 3, 6], drawn);
+        assert!(animation.is_full());
+        animation.finish(|| RgbImage::from_pixel(2, 1, Rgb([9, 0, 0])));
+        let steps = animation.frames().iter().map(|x| x[(0, 0)].0[0]).collect::<Vec<_>>();
+        assert_eq!(vec![0, 3, 9], steps);
+        assert!(Recording::new(0, 1, 1).is_err());
+        assert!(Recording::new(10, 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_point_grid() {
+        let grid = point_grid(2, 3, [(0, 2), (1, 0), (2, 0)]);
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(vec![(0, 2), (1, 0)], grid.find_all(&true).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,425 @@
+use std::cmp::Ordering;
+use num_bigint::BigUint;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cheapest way from the start to a goal: its total cost and the states along it, both ends
+/// included.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Cost of a move. `Default` has to be zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// Dijkstra from `start` until a state with `is_goal` is taken from the queue. `successors` yields
+/// the reachable states with the cost of the move.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* from `start`. `heuristic` must not overestimate the remaining cost and must not drop by more
+/// than the cost of a move, otherwise the path may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut store = HashStore(HashMap::new());
+    let found = search(&mut store, start, successors, heuristic, is_goal, false);
+    first_path(&store, found)
+}
+
+/// [`dijkstra`] over states that `index` maps to distinct values below `size`, like the cells
+/// of a grid. Costs are kept in a `Vec` instead of a `HashMap`.
+pub fn dense_dijkstra<S, C, I>(
+    size: usize,
+    index: impl Fn(&S) -> usize,
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    dense_astar(size, index, start, successors, |_| C::default(), is_goal)
+}
+
+/// [`astar`] over states that `index` maps to distinct values below `size`.
+pub fn dense_astar<S, C, I>(
+    size: usize,
+    index: impl Fn(&S) -> usize,
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut store = DenseStore::new(size, index);
+    let found = search(&mut store, start, successors, heuristic, is_goal, false);
+    first_path(&store, found)
+}
+
+/// [`dijkstra`] that keeps every cheapest path to the goals instead of one. Moves must cost more
+/// than zero.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<OptimalPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut store = HashStore(HashMap::new());
+    let found = search(&mut store, start, successors, |_| C::default(), is_goal, true);
+    OptimalPaths::collect(&store, found)
+}
+
+/// [`dijkstra_all`] over states that `index` maps to distinct values below `size`.
+pub fn dense_dijkstra_all<S, C, I>(
+    size: usize,
+    index: impl Fn(&S) -> usize,
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<OptimalPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut store = DenseStore::new(size, index);
+    let found = search(&mut store, start, successors, |_| C::default(), is_goal, true);
+    OptimalPaths::collect(&store, found)
+}
+
+/// Cheapest known cost of a state and the states it was reached from with that cost.
+struct Visit<S, C> {
+    cost: C,
+    parent: Option<S>,
+    /// Further predecessors with the same cost, only collected when all paths are wanted.
+    others: Vec<S>,
+}
+
+impl<S, C> Visit<S, C> {
+    fn new(cost: C, parent: Option<S>) -> Self {
+        Self { cost, parent, others: vec![] }
+    }
+
+    fn parents(&self) -> impl Iterator<Item = &S> {
+        self.parent.iter().chain(&self.others)
+    }
+}
+
+trait Store<S, C> {
+    fn get(&self, state: &S) -> Option<&Visit<S, C>>;
+    fn get_mut(&mut self, state: &S) -> Option<&mut Visit<S, C>>;
+    fn insert(&mut self, state: S, visit: Visit<S, C>);
+}
+
+struct HashStore<S, C>(HashMap<S, Visit<S, C>>);
+
+impl<S: Eq + Hash, C> Store<S, C> for HashStore<S, C> {
+    fn get(&self, state: &S) -> Option<&Visit<S, C>> {
+        self.0.get(state)
+    }
+
+    fn get_mut(&mut self, state: &S) -> Option<&mut Visit<S, C>> {
+        self.0.get_mut(state)
+    }
+
+    fn insert(&mut self, state: S, visit: Visit<S, C>) {
+        self.0.insert(state, visit);
+    }
+}
+
+struct DenseStore<S, C, F> {
+    index: F,
+    visits: Vec<Option<Visit<S, C>>>,
+}
+
+impl<S, C, F: Fn(&S) -> usize> DenseStore<S, C, F> {
+    fn new(size: usize, index: F) -> Self {
+        Self { index, visits: (0..size).map(|_| None).collect() }
+    }
+}
+
+impl<S, C, F: Fn(&S) -> usize> Store<S, C> for DenseStore<S, C, F> {
+    fn get(&self, state: &S) -> Option<&Visit<S, C>> {
+        self.visits[(self.index)(state)].as_ref()
+    }
+
+    fn get_mut(&mut self, state: &S) -> Option<&mut Visit<S, C>> {
+        self.visits[(self.index)(state)].as_mut()
+    }
+
+    fn insert(&mut self, state: S, visit: Visit<S, C>) {
+        let index = (self.index)(&state);
+        self.visits[index] = Some(visit);
+    }
+}
+
+/// Queue entry ordered by the estimated total cost, the cheapest first.
+struct Entry<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed for the max-heap. On a tie the deeper state goes first.
+        other.estimate.cmp(&self.estimate).then(self.cost.cmp(&other.cost))
+    }
+}
+
+/// Fills `store` and returns the cost of the cheapest goal with the goals reached at that cost.
+/// Stops at the first goal unless `all` is set, then it also records every equal-cost predecessor
+/// and keeps going until the queue only holds more expensive states. Goals are not expanded then.
+fn search<S, C, I>(
+    store: &mut impl Store<S, C>,
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    all: bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut que = BinaryHeap::new();
+    que.push(Entry { estimate: heuristic(&start), cost: C::default(), state: start.clone() });
+    store.insert(start, Visit::new(C::default(), None));
+    let mut found: Option<(C, Vec<S>)> = None;
+
+    while let Some(Entry { estimate, cost, state }) = que.pop() {
+        if found.as_ref().is_some_and(|(best, _)| *best < estimate) {
+            break;
+        }
+        // A cheaper way to the state was queued after this one.
+        if store.get(&state).is_some_and(|x| x.cost < cost) {
+            continue;
+        }
+        if is_goal(&state) {
+            if !all {
+                return Some((cost, vec![state]));
+            }
+            found.get_or_insert_with(|| (cost, vec![])).1.push(state);
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match store.get_mut(&next) {
+                Some(visit) if visit.cost < next_cost => continue,
+                Some(visit) if visit.cost == next_cost => {
+                    if all {
+                        visit.others.push(state.clone());
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            store.insert(next.clone(), Visit::new(next_cost, Some(state.clone())));
+            que.push(Entry { estimate: next_cost + heuristic(&next), cost: next_cost, state: next });
+        }
+    }
+    found
+}
+
+fn reconstruct<S: Clone, C>(store: &impl Store<S, C>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(parent) = store.get(states.last().unwrap()).and_then(|x| x.parent.clone()) {
+        states.push(parent);
+    }
+    states.reverse();
+    states
+}
+
+fn first_path<S: Clone, C>(store: &impl Store<S, C>, found: Option<(C, Vec<S>)>) -> Option<Path<S, C>> {
+    let (cost, goals) = found?;
+    let goal = goals.into_iter().next()?;
+    Some(Path { cost, states: reconstruct(store, goal) })
+}
+
+/// Every cheapest way from the start to a goal, as the graph of the predecessors of the states on
+/// them.
+#[derive(Clone, Debug)]
+pub struct OptimalPaths<S, C> {
+    pub cost: C,
+    /// Goals reached with `cost`.
+    pub goals: Vec<S>,
+    /// Cost and predecessors on an optimal path of every state on one, the start has none.
+    predecessors: HashMap<S, (C, Vec<S>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> OptimalPaths<S, C> {
+    fn collect(store: &impl Store<S, C>, found: Option<(C, Vec<S>)>) -> Option<Self> {
+        let (cost, goals) = found?;
+        let mut predecessors = HashMap::new();
+        let mut stack = goals.clone();
+        while let Some(state) = stack.pop() {
+            if predecessors.contains_key(&state) {
+                continue;
+            }
+            let visit = store.get(&state).unwrap();
+            let parents = visit.parents().cloned().collect::<Vec<_>>();
+            stack.extend(parents.iter().cloned());
+            predecessors.insert(state, (visit.cost, parents));
+        }
+        Some(Self { cost, goals, predecessors })
+    }
+
+    /// States on any optimal path, the start and the goals included.
+    pub fn states(&self) -> impl Iterator<Item = &S> {
+        self.predecessors.keys()
+    }
+
+    /// Distinct `node(state)` of the states on any optimal path, like the positions of grid states
+    /// that also hold a direction.
+    pub fn nodes<N: Eq + Hash>(&self, node: impl FnMut(&S) -> N) -> HashSet<N> {
+        self.states().map(node).collect()
+    }
+
+    /// Predecessors of `state` on an optimal path, empty for the start and states on none.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map(|(_, x)| x.as_slice()).unwrap_or_default()
+    }
+
+    /// Number of distinct optimal paths to any of the goals.
+    pub fn count(&self) -> BigUint {
+        let mut order = self.predecessors.iter().collect::<Vec<_>>();
+        order.sort_by_key(|(_, (cost, _))| *cost);
+        let mut counts: HashMap<&S, BigUint> = HashMap::new();
+        for (state, (_, parents)) in order {
+            let count = if parents.is_empty() {
+                BigUint::from(1u8)
+            } else {
+                parents.iter().map(|x| &counts[x]).sum()
+            };
+            counts.insert(state, count);
+        }
+        self.goals.iter().map(|x| &counts[x]).sum()
+    }
+
+    /// Enumerates the optimal paths from the start to a goal. There may be exponentially many.
+    pub fn paths(&self) -> impl Iterator<Item = Vec<S>> + '_ {
+        // Depth first from the goals backwards, every entry holds the length of the path after it.
+        let mut stack = self.goals.iter().map(|x| (x, 0)).collect::<Vec<_>>();
+        let mut path: Vec<&S> = vec![];
+        std::iter::from_fn(move || {
+            while let Some((state, depth)) = stack.pop() {
+                path.truncate(depth);
+                path.push(state);
+                let parents = self.predecessors(state);
+                if parents.is_empty() {
+                    return Some(path.iter().rev().map(|x| (*x).clone()).collect());
+                }
+                stack.extend(parents.iter().map(|x| (x, depth + 1)));
+            }
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 -> 1 -> 3 costs 1 + 5, 0 -> 2 -> 3 costs 2 + 2.
+    fn edges(state: &usize) -> Vec<(usize, usize)> {
+        match state {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(3, 5)],
+            2 => vec![(3, 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(0usize, edges, |x| *x == 3).unwrap();
+        assert_eq!(Path { cost: 4, states: vec![0, 2, 3] }, path);
+        assert_eq!(None, dijkstra(1usize, edges, |x| *x == 2));
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        // Diamond 0 -> {1, 2} -> 3 with equal costs, and a dearer detour 0 -> 4 -> 3.
+        let successors = |state: &usize| match state {
+            0 => vec![(1, 1), (2, 1), (4, 1)],
+            1 | 2 => vec![(3, 2)],
+            4 => vec![(3, 5)],
+            _ => vec![],
+        };
+        let paths = dijkstra_all(0usize, successors, |x| *x == 3).unwrap();
+        assert_eq!(3, paths.cost);
+        assert_eq!(BigUint::from(2u8), paths.count());
+        assert_eq!(HashSet::from([0, 1, 2, 3]), paths.nodes(|x| *x));
+        let mut all = paths.paths().collect::<Vec<_>>();
+        all.sort();
+        assert_eq!(vec![vec![0, 1, 3], vec![0, 2, 3]], all);
+        assert_eq!(&[] as &[usize], paths.predecessors(&4));
+    }
+
+    #[test]
+    fn test_dense_astar_on_grid() {
+        // 3x3 grid with a wall in the middle column except for the bottom row.
+        let walls = [(0, 1), (1, 1)];
+        let successors = |&(i, j): &(usize, usize)| {
+            [(0isize, 1isize), (1, 0), (0, -1), (-1, 0)]
+                .into_iter()
+                .filter_map(move |(di, dj)| Some((i.checked_add_signed(di)?, j.checked_add_signed(dj)?)))
+                .filter(|(i, j)| *i < 3 && *j < 3 && !walls.contains(&(*i, *j)))
+                .map(|x| (x, 1usize))
+        };
+        let heuristic = |&(i, j): &(usize, usize)| i.abs_diff(0) + j.abs_diff(2);
+        let path = dense_astar(9, |(i, j)| i * 3 + j, (0, 0), successors, heuristic, |x| *x == (0, 2)).unwrap();
+        assert_eq!(6, path.cost);
+        assert_eq!(vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2)], path.states);
+        assert_eq!(path.cost, dense_dijkstra(9, |(i, j)| i * 3 + j, (0, 0), successors, |x| *x == (0, 2)).unwrap().cost);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::parse::{at_line, ParseError};
+use crate::Grid;
+use anyhow::*;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// Block of consecutive non-blank input lines.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Section {
+    /// Position of the section in the input, starting with 1.
+    pub number: usize,
+    /// Name given by [`Sections::expect`], used in errors.
+    pub name: Option<String>,
+    /// `0` for a block that starts with a `0:` header line. The header is not part of `lines`.
+    pub label: Option<String>,
+    /// Input line number of the first line in `lines`, starting with 1.
+    pub first_line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Section {
+    /// Parses every line with `f`. Errors are placed on their input line and name the section.
+    pub fn parse_lines<T>(&self, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| f(line).map_err(|x| self.locate(at_line(x, i + 1, line))))
+            .collect()
+    }
+
+    /// Parses every line with `FromStr`.
+    pub fn parse_each<T: FromStr>(&self) -> Result<Vec<T>>
+    where
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.parse_lines(|line| Ok(line.parse()?))
+    }
+
+    /// Parses the whole block with `f`. Errors name the section and its first line.
+    pub fn parse<T>(&self, f: impl FnOnce(&[String]) -> Result<T>) -> Result<T> {
+        f(&self.lines).with_context(|| format!("{}, starting at line {}", self, self.first_line))
+    }
+
+    /// Grid of the block, converting every char with `cell`. Errors are placed on their input line
+    /// and name the section.
+    pub fn parse_grid<T>(&self, cell: impl FnMut(char) -> Result<T>) -> Result<Grid<T>> {
+        Grid::from_lines(&self.lines, cell).map_err(|x| self.locate(x))
+    }
+
+    /// Moves a `ParseError` from its line in the section to its input line and prefixes the message
+    /// with the section, other errors get the section as context.
+    fn locate(&self, mut error: Error) -> Error {
+        match error.downcast_mut::<ParseError>() {
+            Some(parse_error) => {
+                parse_error.line += self.first_line - 1;
+                parse_error.message = format!("{self}: {}", parse_error.message);
+                error
+            }
+            None => error.context(self.to_string()),
+        }
+    }
+}
+
+/// `Section 2 (moves)` or `Section 3 (0:)`.
+impl Display for Section {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Section {}", self.number)?;
+        match (&self.name, &self.label) {
+            (Some(name), _) => write!(f, " ({name})"),
+            (None, Some(label)) => write!(f, " ({label}:)"),
+            (None, None) => std::fmt::Result::Ok(()),
+        }
+    }
+}
+
+/// Sections of an input in order. Blank lines separate them, repeated blank lines count as one.
+#[derive(Clone, Debug)]
+pub struct Sections {
+    sections: VecDeque<Section>,
+}
+
+impl Sections {
+    /// Reads the whole input. A first line that is only `label:` is taken as a header.
+    pub fn read<R: BufRead>(reader: R) -> Result<Self> {
+        let mut sections = VecDeque::new();
+        let mut current: Option<Section> = None;
+        for (i, line) in reader.lines().enumerate() {
+            let line = line.with_context(|| format!("Can not read line {}", i + 1))?;
+            if line.trim().is_empty() {
+                sections.extend(current.take());
+                continue;
+            }
+            match current.as_mut() {
+                Some(section) => section.lines.push(line),
+                None => {
+                    let label = line.strip_suffix(':').filter(|x| !x.contains(char::is_whitespace));
+                    let mut section = Section {
+                        number: sections.len() + 1,
+                        name: None,
+                        label: label.map(|x| x.to_string()),
+                        first_line: i + 1,
+                        lines: vec![],
+                    };
+                    if section.label.is_some() {
+                        section.first_line += 1;
+                    } else {
+                        section.lines.push(line);
+                    }
+                    current = Some(section);
+                }
+            }
+        }
+        sections.extend(current);
+        Ok(Self { sections })
+    }
+
+    /// Next section, named `name` in its errors. Fails if there is none left.
+    pub fn expect(&mut self, name: &str) -> Result<Section> {
+        let mut section = self.sections.pop_front().context(format!("Missing section ({name})"))?;
+        section.name = Some(name.to_string());
+        Ok(section)
+    }
+
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+}
+
+impl Iterator for Sections {
+    type Item = Section;
+
+    fn next(&mut self) -> Option<Section> {
+        self.sections.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "\
+0:
+#.
+.#
+
+1:
+##
+
+
+4x4: 0 1
+5x2: 1 x
+";
+
+    #[test]
+    fn test_read_sections() {
+        let sections = Sections::read(TEST.as_bytes()).unwrap().collect::<Vec<_>>();
+        assert_eq!(3, sections.len());
+        assert_eq!((Some("0"), 2, 2), (sections[0].label.as_deref(), sections[0].first_line, sections[0].lines.len()));
+        assert_eq!((Some("1"), 6, 2), (sections[1].label.as_deref(), sections[1].first_line, sections[1].number));
+        assert_eq!((None, 9), (sections[2].label.as_deref(), sections[2].first_line));
+        let grid = sections[0].parse_grid(|x| Ok(x == '#')).unwrap();
+        assert_eq!(vec![(0, 0), (1, 1)], grid.find_all(&true).collect::<Vec<_>>());
+        let error = sections[0].parse_grid(|x| if x == '#' { Ok(x) } else { Err(anyhow!("not a wall")) });
+        assert_eq!("2:2: Section 1 (0:): not a wall", error.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_errors_name_the_section() {
+        let mut sections = Sections::read(TEST.as_bytes()).unwrap();
+        sections.next();
+        assert_eq!(vec!["##"], sections.next().unwrap().parse_each::<String>().unwrap());
+        let regions = sections.expect("regions").unwrap();
+        let counts = regions.parse_lines(|line| {
+            let (_, counts) = line.split_once(": ").context("No counts")?;
+            Ok(counts.split(' ').map(|x| x.parse::<usize>()).collect::<std::result::Result<Vec<_>, _>>()?)
+        });
+        let error = counts.unwrap_err();
+        assert_eq!("10:1: Section 3 (regions): invalid digit found in string", error.to_string());
+        assert_eq!("Missing section (moves)", sections.expect("moves").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,214 @@
+use crate::dot::Dot;
+use crate::params::Params;
+use crate::render::Renderer;
+use crate::{start_day, Answer};
+use anyhow::*;
+use std::any::Any;
+use std::io::BufRead;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// A solved puzzle: parses the input once and answers both parts from the parsed value.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// The last day of a year has only one part.
+    const HAS_PART2: bool = true;
+
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input>;
+
+    /// [`Solution::parse`] with the puzzle parameters, for days whose statement gives values
+    /// apart from the input. Other days fail if any parameter is given.
+    fn parse_with<R: BufRead>(reader: R, params: &Params) -> Result<Self::Input> {
+        if !params.is_empty() {
+            return Err(anyhow!("Day {} of {} takes no parameters, got {}", Self::DAY, Self::YEAR, params));
+        }
+        Self::parse(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+
+    fn part2(_input: &Self::Input) -> Result<Self::Part2> {
+        Err(anyhow!("Day {} of {} has no second part", Self::DAY, Self::YEAR))
+    }
+
+    /// Graph of the input for Graphviz, with the nodes that make up the answers highlighted.
+    fn dot(_input: &Self::Input) -> Result<Dot> {
+        Err(anyhow!("Day {} of {} has no graph to export", Self::DAY, Self::YEAR))
+    }
+
+    /// Draws the input and the answers as images with `renderer`, returns the saved files.
+    fn render(_input: &Self::Input, _renderer: &Renderer) -> Result<Vec<PathBuf>> {
+        Err(anyhow!("Day {} of {} has no images to render", Self::DAY, Self::YEAR))
+    }
+
+    /// Listing of the program in the input and of its execution, for days that run one.
+    fn trace(_input: &Self::Input) -> Result<String> {
+        Err(anyhow!("Day {} of {} has no program to trace", Self::DAY, Self::YEAR))
+    }
+}
+
+/// Type-erased [`Solution`], so solutions of every day can be kept in one registry.
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub has_part2: bool,
+    parse: fn(&mut dyn BufRead, &Params) -> Result<Box<dyn Any>>,
+    part1: fn(&dyn Any) -> Result<Answer>,
+    part2: fn(&dyn Any) -> Result<Answer>,
+    dot: fn(&dyn Any) -> Result<Dot>,
+    render: fn(&dyn Any, &Renderer) -> Result<Vec<PathBuf>>,
+    trace: fn(&dyn Any) -> Result<String>,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution>() -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            has_part2: S::HAS_PART2,
+            parse: parse_input::<S>,
+            part1: solve_part1::<S>,
+            part2: solve_part2::<S>,
+            dot: export_dot::<S>,
+            render: render_images::<S>,
+            trace: trace_program::<S>,
+        }
+    }
+
+    /// Parses with the parameters of the real puzzle.
+    pub fn parse(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>> {
+        self.parse_with(reader, &Params::new())
+    }
+
+    pub fn parse_with(&self, reader: &mut dyn BufRead, params: &Params) -> Result<Box<dyn Any>> {
+        (self.parse)(reader, params)
+    }
+
+    /// `input` has to be the value returned by [`Puzzle::parse`] of the same puzzle.
+    pub fn part1(&self, input: &dyn Any) -> Result<Answer> {
+        (self.part1)(input)
+    }
+
+    /// `input` has to be the value returned by [`Puzzle::parse`] of the same puzzle.
+    pub fn part2(&self, input: &dyn Any) -> Result<Answer> {
+        (self.part2)(input)
+    }
+
+    /// `input` has to be the value returned by [`Puzzle::parse`] of the same puzzle.
+    pub fn dot(&self, input: &dyn Any) -> Result<Dot> {
+        (self.dot)(input)
+    }
+
+    /// `input` has to be the value returned by [`Puzzle::parse`] of the same puzzle.
+    pub fn render(&self, input: &dyn Any, renderer: &Renderer) -> Result<Vec<PathBuf>> {
+        (self.render)(input, renderer)
+    }
+
+    /// `input` has to be the value returned by [`Puzzle::parse`] of the same puzzle.
+    pub fn trace(&self, input: &dyn Any) -> Result<String> {
+        (self.trace)(input)
+    }
+}
+
+fn parse_input<S: Solution>(reader: &mut dyn BufRead, params: &Params) -> Result<Box<dyn Any>>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse_with(reader, params)?))
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> Result<&S::Input>
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .context(format!("Input was not parsed by day {} of {}", S::DAY, S::YEAR))
+}
+
+fn solve_part1<S: Solution>(input: &dyn Any) -> Result<Answer>
+where
+    S::Input: 'static,
+{
+    Ok(S::part1(downcast_input::<S>(input)?)?.into())
+}
+
+fn solve_part2<S: Solution>(input: &dyn Any) -> Result<Answer>
+where
+    S::Input: 'static,
+{
+    Ok(S::part2(downcast_input::<S>(input)?)?.into())
+}
+
+fn export_dot<S: Solution>(input: &dyn Any) -> Result<Dot>
+where
+    S::Input: 'static,
+{
+    S::dot(downcast_input::<S>(input)?)
+}
+
+fn render_images<S: Solution>(input: &dyn Any, renderer: &Renderer) -> Result<Vec<PathBuf>>
+where
+    S::Input: 'static,
+{
+    S::render(downcast_input::<S>(input)?, renderer)
+}
+
+fn trace_program<S: Solution>(input: &dyn Any) -> Result<String>
+where
+    S::Input: 'static,
+{
+    S::trace(downcast_input::<S>(input)?)
+}
+
+/// Parses the input once with `params` and answers the requested part, or every part of the day
+/// if `part` is `None`. Prints one line per step with its timing and returns the answers by part.
+pub fn run_puzzle(
+    puzzle: &Puzzle,
+    reader: &mut dyn BufRead,
+    part: Option<u8>,
+    params: &Params,
+) -> Result<Vec<(u8, Answer)>> {
+    let parts = match part {
+        None if puzzle.has_part2 => vec![1, 2],
+        None | Some(1) => vec![1],
+        Some(2) if puzzle.has_part2 => vec![2],
+        Some(part) => {
+            return Err(anyhow!("Day {} of {} has no part {}", puzzle.day, puzzle.year, part));
+        }
+    };
+    start_day(puzzle.year, puzzle.day);
+
+    let (input, elapsed) = timed(|| puzzle.parse_with(reader, params))?;
+    print_step("Parse", elapsed, "");
+
+    let mut answers = vec![];
+    for part in parts {
+        let (answer, elapsed) = match part {
+            1 => timed(|| puzzle.part1(input.as_ref()))?,
+            _ => timed(|| puzzle.part2(input.as_ref()))?,
+        };
+        print_step(&format!("Part {part}"), elapsed, &answer.to_string());
+        answers.push((part, answer));
+    }
+    Ok(answers)
+}
+
+fn timed<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let start = Instant::now();
+    let value = f()?;
+    Ok((value, start.elapsed()))
+}
+
+fn print_step(step: &str, elapsed: Duration, answer: &str) {
+    let line = format!("{:<8}{:>12.3?}  {}", step, elapsed, answer);
+    println!("{}", line.trim_end());
+}
@@ -0,0 +1,228 @@
+use num_bigint::BigUint;
+use std::collections::{HashMap, VecDeque};
+
+const ROOT: usize = 0;
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    children: HashMap<u8, usize>,
+    /// Longest proper suffix of this node that is also a node.
+    fail: usize,
+    /// Pattern that ends at this node.
+    pattern: Option<usize>,
+    /// Nearest node on the `fail` chain that ends a pattern.
+    output: Option<usize>,
+}
+
+/// Occurrence of a pattern in a text, `start..end` in bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Trie of patterns with the Aho–Corasick links to find all of them in a text in one pass.
+/// Patterns get ids in the order they are given, empty and repeated patterns are skipped.
+#[derive(Clone, Debug)]
+pub struct Trie {
+    nodes: Vec<Node>,
+    patterns: Vec<String>,
+}
+
+impl Trie {
+    pub fn new<S: AsRef<str>>(patterns: impl IntoIterator<Item = S>) -> Self {
+        let mut trie = Self { nodes: vec![Node::default()], patterns: vec![] };
+        for pattern in patterns {
+            trie.insert(pattern.as_ref());
+        }
+        trie.link();
+        trie
+    }
+
+    fn insert(&mut self, pattern: &str) {
+        if pattern.is_empty() {
+            return;
+        }
+        let mut node = ROOT;
+        for byte in pattern.bytes() {
+            node = match self.nodes[node].children.get(&byte) {
+                Some(child) => *child,
+                None => {
+                    self.nodes.push(Node::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(byte, child);
+                    child
+                }
+            };
+        }
+        if self.nodes[node].pattern.is_none() {
+            self.nodes[node].pattern = Some(self.patterns.len());
+            self.patterns.push(pattern.to_string());
+        }
+    }
+
+    /// Sets the failure and output links breadth first, so the links of shorter nodes are ready.
+    fn link(&mut self) {
+        let mut que = self.nodes[ROOT].children.values().copied().collect::<VecDeque<_>>();
+        while let Some(node) = que.pop_front() {
+            let children = self.nodes[node].children.iter().map(|(x, y)| (*x, *y)).collect::<Vec<_>>();
+            for (byte, child) in children {
+                let fail = self.next(self.nodes[node].fail, byte);
+                self.nodes[child].fail = fail;
+                self.nodes[child].output = self.nodes[fail].pattern.map(|_| fail).or(self.nodes[fail].output);
+                que.push_back(child);
+            }
+        }
+    }
+
+    /// Node reached from `node` by `byte`, falling back along the failure links.
+    fn next(&self, mut node: usize, byte: u8) -> usize {
+        loop {
+            if let Some(child) = self.nodes[node].children.get(&byte) {
+                return *child;
+            }
+            if node == ROOT {
+                return ROOT;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn pattern(&self, id: usize) -> &str {
+        &self.patterns[id]
+    }
+
+    /// Whether `word` is one of the patterns.
+    pub fn contains(&self, word: &str) -> bool {
+        let mut node = ROOT;
+        for byte in word.bytes() {
+            match self.nodes[node].children.get(&byte) {
+                Some(child) => node = *child,
+                None => return false,
+            }
+        }
+        self.nodes[node].pattern.is_some()
+    }
+
+    /// Every occurrence of every pattern, overlapping ones included, ordered by their end and the
+    /// longest first for the same end.
+    pub fn find_all(&self, text: &str) -> Vec<Match> {
+        let mut matches = vec![];
+        let mut node = ROOT;
+        for (i, byte) in text.bytes().enumerate() {
+            node = self.next(node, byte);
+            let mut current = self.nodes[node].pattern.map(|_| node).or(self.nodes[node].output);
+            while let Some(found) = current {
+                let pattern = self.nodes[found].pattern.unwrap();
+                let end = i + 1;
+                matches.push(Match { pattern, start: end - self.patterns[pattern].len(), end });
+                current = self.nodes[found].output;
+            }
+        }
+        matches
+    }
+
+    /// Starts of the patterns ending at every byte offset of `text`, `0..=len`.
+    fn starts_by_end(&self, text: &str) -> Vec<Vec<usize>> {
+        let mut starts = vec![vec![]; text.len() + 1];
+        for found in self.find_all(text) {
+            starts[found.end].push(found.start);
+        }
+        starts
+    }
+
+    /// Whether `text` can be split into a sequence of patterns.
+    pub fn can_segment(&self, text: &str) -> bool {
+        let mut reachable = vec![false; text.len() + 1];
+        reachable[0] = true;
+        for (end, starts) in self.starts_by_end(text).iter().enumerate().skip(1) {
+            reachable[end] = starts.iter().any(|x| reachable[*x]);
+        }
+        reachable[text.len()]
+    }
+
+    /// Number of ways to split `text` into a sequence of patterns.
+    pub fn count_segmentations(&self, text: &str) -> BigUint {
+        let mut counts = vec![BigUint::default(); text.len() + 1];
+        counts[0] = BigUint::from(1u8);
+        for (end, starts) in self.starts_by_end(text).iter().enumerate().skip(1) {
+            counts[end] = starts.iter().map(|x| &counts[*x]).sum();
+        }
+        counts.swap_remove(text.len())
+    }
+
+    /// Every way to split `text` into a sequence of patterns. There may be exponentially many.
+    pub fn segmentations<'a>(&self, text: &'a str) -> Vec<Vec<&'a str>> {
+        // Walk back from the end so that every branch leads to a full segmentation.
+        let starts = self.starts_by_end(text);
+        let mut reachable = vec![false; text.len() + 1];
+        reachable[0] = true;
+        for end in 1..=text.len() {
+            reachable[end] = starts[end].iter().any(|x| reachable[*x]);
+        }
+
+        let mut segmentations = vec![];
+        if !reachable[text.len()] {
+            return segmentations;
+        }
+        // Ends of the pieces chosen so far with the index of the next start to try at each.
+        let mut ends = vec![(text.len(), 0)];
+        while let Some(&(end, i)) = ends.last() {
+            if end == 0 {
+                let pieces = ends.windows(2).rev().map(|x| &text[x[1].0..x[0].0]);
+                segmentations.push(pieces.collect());
+                ends.pop();
+                continue;
+            }
+            match starts[end].get(i) {
+                Some(start) => {
+                    ends.last_mut().unwrap().1 += 1;
+                    if reachable[*start] {
+                        ends.push((*start, 0));
+                    }
+                }
+                None => {
+                    ends.pop();
+                }
+            }
+        }
+        segmentations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_all() {
+        let trie = Trie::new(["he", "she", "his", "hers", "", "he"]);
+        assert_eq!(4, trie.len());
+        assert!(trie.contains("she") && !trie.contains("sh") && !trie.contains(""));
+        let found = trie.find_all("ushers").into_iter().map(|x| (trie.pattern(x.pattern), x.start)).collect::<Vec<_>>();
+        assert_eq!(vec![("she", 1), ("he", 2), ("hers", 2)], found);
+    }
+
+    #[test]
+    fn test_segmentations() {
+        let trie = Trie::new(["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]);
+        assert!(trie.can_segment("brwrr"));
+        assert!(!trie.can_segment("ubwu"));
+        assert_eq!(BigUint::from(2u8), trie.count_segmentations("brwrr"));
+        assert_eq!(BigUint::from(6u8), trie.count_segmentations("rrbgbr"));
+        let mut all = trie.segmentations("brwrr");
+        all.sort();
+        assert_eq!(vec![vec!["b", "r", "wr", "r"], vec!["br", "wr", "r"]], all);
+        assert!(trie.segmentations("bbrgwb").is_empty());
+        assert_eq!(BigUint::from(1u8), trie.count_segmentations(""));
+    }
+}
@@ -0,0 +1,117 @@
+/// Disjoint sets of the ids `0..len`, with path compression and union by size.
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// Every id in its own set.
+    pub fn new(len: usize) -> Self {
+        Self { parents: (0..len).collect(), sizes: vec![1; len], count: len }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Representative of the set of `id`.
+    pub fn find(&mut self, mut id: usize) -> usize {
+        while self.parents[id] != id {
+            // Path halving: every visited id skips its parent from now on.
+            self.parents[id] = self.parents[self.parents[id]];
+            id = self.parents[id];
+        }
+        id
+    }
+
+    /// Merges the sets of `a` and `b`. Returns `false` if they were already one set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the set of `id`.
+    pub fn size(&mut self, id: usize) -> usize {
+        let root = self.find(id);
+        self.sizes[root]
+    }
+
+    /// Number of sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Size of every set, in the order of their representatives.
+    pub fn sizes(&self) -> Vec<usize> {
+        (0..self.len()).filter(|x| self.parents[*x] == *x).map(|x| self.sizes[x]).collect()
+    }
+}
+
+/// Kruskal over `(weight, a, b)` edges between the ids `0..len`: the edges of a minimum spanning
+/// forest in the order they merge two components. Edges of equal weight keep their order, and the
+/// iteration stops once everything is connected.
+pub fn kruskal<W: Ord>(
+    len: usize,
+    edges: impl IntoIterator<Item = (W, usize, usize)>,
+) -> impl Iterator<Item = (W, usize, usize)> {
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut edges = edges.into_iter();
+    let mut sets = UnionFind::new(len);
+    std::iter::from_fn(move || {
+        while sets.count() > 1 {
+            let (weight, a, b) = edges.next()?;
+            if sets.union(a, b) {
+                return Some((weight, a, b));
+            }
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 1));
+        assert!(!sets.union(0, 2));
+        assert!(sets.union(3, 4));
+        assert!(sets.connected(0, 2) && !sets.connected(0, 3));
+        assert_eq!(3, sets.size(1));
+        assert_eq!(3, sets.count());
+        let mut sizes = sets.sizes();
+        sizes.sort();
+        assert_eq!(vec![1, 2, 3], sizes);
+    }
+
+    #[test]
+    fn test_kruskal() {
+        let edges = vec![(4, 0, 1), (1, 1, 2), (3, 0, 2), (2, 2, 3), (5, 3, 0), (9, 1, 3)];
+        let tree = kruskal(4, edges).collect::<Vec<_>>();
+        assert_eq!(vec![(1, 1, 2), (2, 2, 3), (3, 0, 2)], tree);
+        assert_eq!(vec![(1, 0, 1)], kruskal(3, vec![(1, 0, 1)]).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,29 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::Solution;
+use crate::parse::parse_lines;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const YEAR: u16 = 2025; // TODO: Fill the year
+    const DAY: u8 = 0; // TODO: Fill the day
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        // TODO: Parse every line, errors are placed on their line
+        parse_lines(reader, |line| Ok(line.to_string()))
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        // TODO: Solve Part 1 of the puzzle
+        Ok(input.len())
+    }
+
+    // fn part2(input: &Self::Input) -> Result<usize> {
+    //     Ok(0)
+    // }
+}
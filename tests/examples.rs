@@ -0,0 +1,20 @@
+use adv_code_2024::{days, Params};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Runs `part` of the day on an example file from `examples/` with its `params` and compares the
+/// answer.
+fn check_example(year: u16, day: u8, path: &str, params: &str, part: u8, expected: &str) {
+    let puzzle = days::find(year, day).unwrap_or_else(|| panic!("Day {day} of {year} is not registered"));
+    let file = File::open(Path::new(env!("CARGO_MANIFEST_DIR")).join(path)).unwrap();
+    let params = params.parse::<Params>().unwrap();
+    let input = puzzle.parse_with(&mut BufReader::new(file), &params).unwrap();
+    let answer = match part {
+        1 => puzzle.part1(input.as_ref()),
+        _ => puzzle.part2(input.as_ref()),
+    };
+    assert_eq!(expected, answer.unwrap().to_string(), "{path}, part {part}");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,136 @@
+use adv_code_2024::days;
+use adv_code_2024::parse::in_file;
+use std::path::Path;
+
+/// Error of the day on `input`: from the parser, or else from the first part that fails. Panics
+/// if the day accepts the input.
+fn error(year: u16, day: u8, input: &str) -> String {
+    let puzzle = days::find(year, day).unwrap_or_else(|| panic!("Day {day} of {year} is not registered"));
+    let result = puzzle.parse(&mut input.as_bytes()).and_then(|x| {
+        puzzle.part1(x.as_ref())?;
+        puzzle.part2(x.as_ref())
+    });
+    match result {
+        Ok(answer) => panic!("{year}/{day:02} accepted {input:?} with {answer}"),
+        Err(error) => error.to_string(),
+    }
+}
+
+#[test]
+fn malformed_2024_05() {
+    assert_eq!("2:4: Section 1 (rules): expected a number, found 'x'", error(2024, 5, "47|53\n97|x\n\n75,47\n"));
+    assert_eq!("5:4: Section 2 (updates): expected a number, found '4x'", error(2024, 5, "47|53\n97|13\n\n75,47\n97,4x\n"));
+}
+
+#[test]
+fn malformed_2024_06() {
+    assert_eq!("Start not found", error(2024, 6, "..#\n...\n"));
+    assert_eq!("2:1: expected 3 cells, found 2", error(2024, 6, ".^#\n..\n"));
+}
+
+#[test]
+fn malformed_2024_07() {
+    assert_eq!("2:4: expected a number, found 'x'", error(2024, 7, "3: 1 2\n5: x 2\n"));
+}
+
+#[test]
+fn malformed_2024_08() {
+    assert_eq!("2:1: expected 3 cells, found 2", error(2024, 8, "..a\n.a\n"));
+}
+
+#[test]
+fn malformed_2024_09() {
+    assert_eq!("1:1: expected a disk map, found ''", error(2024, 9, ""));
+    assert_eq!("1:3: expected a digit, found 'x'", error(2024, 9, "12x"));
+}
+
+#[test]
+fn malformed_2024_10() {
+    assert_eq!("2:2: expected a digit, found 'x'", error(2024, 10, "01\n2x\n"));
+}
+
+#[test]
+fn malformed_2024_13() {
+    let input = "Button A: X+94, Y+34\nButton B: X+22, Y+q7\nPrize: X=8400, Y=5400\n";
+    assert_eq!("2:19: Section 1: expected a number, found 'q7'", error(2024, 13, input));
+    let input = "Button A: X+94, Y+34\nPrize: X=8400, Y=5400\n";
+    assert_eq!("Section 1, starting at line 1: expected two buttons and a prize", error(2024, 13, input));
+}
+
+#[test]
+fn malformed_2024_15() {
+    let input = "####\n#@x#\n####\n\n<\n";
+    assert_eq!("2:3: Section 1 (map): expected '#', '@', 'O' or '.', found 'x'", error(2024, 15, input));
+    let input = "####\n#@.#\n####\n\n<>\n^x\n";
+    assert_eq!("6:2: Section 2 (moves): expected <, >, ^ or v, found 'x'", error(2024, 15, input));
+}
+
+#[test]
+fn malformed_2024_16() {
+    assert_eq!("No end 'E' on the map", error(2024, 16, "#S.\n..#\n"));
+}
+
+#[test]
+fn malformed_2024_17() {
+    let input = "Register A: 5\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,5,5\n";
+    assert_eq!("No value of register A makes the program output itself", error(2024, 17, input));
+}
+
+#[test]
+fn malformed_2024_18() {
+    assert_eq!("2:1: coordinates have to be below 71", error(2024, 18, "1,2\n71,0\n"));
+    assert_eq!("1:3: expected a number, found 'y'", error(2024, 18, "1,y\n"));
+}
+
+#[test]
+fn malformed_2024_19() {
+    assert_eq!("Section 1 (towels), starting at line 1", error(2024, 19, "r, wr\nrwr\nwrr\n"));
+    assert_eq!("Missing section (designs)", error(2024, 19, "r, wr\n"));
+}
+
+#[test]
+fn malformed_2024_20() {
+    assert_eq!("No start 'S' on the map", error(2024, 20, "###\n#E#\n###\n"));
+    assert_eq!("1:1: expected a wall '#' on the border, found 'E'", error(2024, 20, "E..\n..S\n"));
+    assert_eq!("2:4: expected a wall '#' on the border, found '.'", error(2024, 20, "####\n#SE.\n####\n"));
+}
+
+#[test]
+fn malformed_2024_21() {
+    assert_eq!("2:2: expected a key of the numeric keypad, found 'x'", error(2024, 21, "029A\n9x0A\n"));
+    assert_eq!("1:1: expected digits followed by A, found '029'", error(2024, 21, "029\n"));
+    assert_eq!("1:4: expected a key of the numeric keypad, found '<'", error(2024, 21, "029<\n"));
+}
+
+#[test]
+fn malformed_2024_25() {
+    assert_eq!("2:2: Section 1: expected '#' or '.', found 'x'", error(2024, 25, "###\n#x.\n"));
+    assert_eq!("6:1: Section 2: expected 3 cells, found 2", error(2024, 25, "###\n#..\n\n...\n#..\n##\n"));
+}
+
+#[test]
+fn malformed_2025_01() {
+    let puzzle = days::find(2025, 1).unwrap();
+    let parse_error = puzzle.parse(&mut "L68\nR30\nX12\n".as_bytes()).unwrap_err();
+    let parse_error = in_file(parse_error, Path::new("input/2025/01.txt"));
+    assert_eq!("input/2025/01.txt:3:1: expected L or R, found 'X'", parse_error.to_string());
+    assert_eq!("2:2: expected a number, found 'x'", error(2025, 1, "L1\nRx\n"));
+}
+
+#[test]
+fn malformed_2025_06() {
+    assert_eq!("2:3: expected a digit, '+', '*' or a space, found 'x'", error(2025, 6, "1 2\n3 x\n"));
+}
+
+#[test]
+fn malformed_2025_07() {
+    assert_eq!("2:1: expected 3 cells, found 2", error(2025, 7, ".S.\n..\n"));
+    assert_eq!("2:1: splitter on the edge of the manifold", error(2025, 7, ".S.\n^..\n"));
+}
+
+#[test]
+fn malformed_2025_12() {
+    assert_eq!("2:4: Section 1 (0:): shapes have to fit in 3x3", error(2025, 12, "0:\n####\n"));
+    assert_eq!("5:1: Section 1 (0:): shapes have to fit in 3x3", error(2025, 12, "0:\n###\n#..\n#..\n#..\n"));
+    assert_eq!("3:2: Section 1 (0:): expected '#' or '.', found 'x'", error(2025, 12, "0:\n###\n#x.\n"));
+}